@@ -0,0 +1,76 @@
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// xorshift64 伪随机数生成器，保证每次运行的输入一致
+pub struct XorShift(u64);
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+pub fn random_vec(len: usize, seed: u64) -> Vec<u64> {
+    let mut rng = XorShift::new(seed);
+    (0..len).map(|_| rng.next_u64()).collect()
+}
+
+thread_local! {
+    static COMPARISONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// 记录比较次数的包装类型
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Counted(pub u64);
+
+impl PartialOrd for Counted {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Counted {
+    fn cmp(&self, other: &Self) -> Ordering {
+        COMPARISONS.with(|c| c.set(c.get() + 1));
+        self.0.cmp(&other.0)
+    }
+}
+
+/// 统计一次排序所用的比较次数
+pub fn count_comparisons(input: &[u64], sort: fn(&mut [Counted])) -> u64 {
+    let mut arr: Vec<Counted> = input.iter().map(|&x| Counted(x)).collect();
+    COMPARISONS.with(|c| c.set(0));
+    sort(&mut arr);
+    COMPARISONS.with(|c| c.get())
+}
+
+/// 统计一段代码执行期间 [`Counted`] 的比较次数
+pub fn comparisons_in(f: impl FnOnce()) -> u64 {
+    COMPARISONS.with(|c| c.set(0));
+    f();
+    COMPARISONS.with(|c| c.get())
+}
+
+/// 多次运行排序，返回单次运行的最短耗时
+pub fn measure<T: Clone>(input: &[T], rounds: usize, mut sort: impl FnMut(&mut [T])) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..rounds {
+        let mut arr = input.to_vec();
+        let start = Instant::now();
+        sort(black_box(&mut arr));
+        best = best.min(start.elapsed());
+        black_box(&arr);
+    }
+    best
+}
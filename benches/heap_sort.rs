@@ -0,0 +1,57 @@
+//! 比较 `heap_sort`（基于 `sink` 的下沉）、`bottom_up_heap_sort` 与 `smooth_sort`
+//!
+//! 运行：`cargo bench --bench heap_sort`
+
+mod common;
+
+use common::{count_comparisons, measure, random_vec};
+use rust_demo::sort::{bottom_up_heap_sort, heap_sort, smooth_sort};
+
+const LEN: usize = 100_000;
+const ROUNDS: usize = 10;
+
+fn main() {
+    let random = random_vec(LEN, 42);
+    let mut sorted = random.clone();
+    sorted.sort();
+    let mut reversed = sorted.clone();
+    reversed.reverse();
+
+    println!(
+        "{:<10} {:<22} {:>12} {:>14}",
+        "input", "algorithm", "time (ms)", "comparisons"
+    );
+    for (name, input) in [
+        ("random", &random),
+        ("sorted", &sorted),
+        ("reversed", &reversed),
+    ] {
+        bench(name, "heap_sort (sink)", input, heap_sort, heap_sort);
+        bench(
+            name,
+            "bottom_up_heap_sort",
+            input,
+            bottom_up_heap_sort,
+            bottom_up_heap_sort,
+        );
+        bench(name, "smooth_sort", input, smooth_sort, smooth_sort);
+    }
+}
+
+fn bench(
+    input_name: &str,
+    algorithm: &str,
+    input: &[u64],
+    sort: fn(&mut [u64]),
+    counted_sort: fn(&mut [common::Counted]),
+) {
+    let time = measure(input, ROUNDS, sort);
+    let comparisons = count_comparisons(input, counted_sort);
+    println!(
+        "{:<10} {:<22} {:>12.3} {:>14}",
+        input_name,
+        algorithm,
+        time.as_secs_f64() * 1000.0,
+        comparisons
+    );
+}
@@ -0,0 +1,68 @@
+//! 比较 `quick_sort`（分支切分 `partition`）与 `block_quick_sort`（块切分）
+//!
+//! 运行：`cargo bench --bench quick_sort`
+
+mod common;
+
+use std::cmp::Ordering;
+
+use common::{measure, random_vec};
+use rust_demo::sort::{block_quick_sort, quick_sort};
+
+const LEN: usize = 1_000_000;
+const ROUNDS: usize = 5;
+
+/// 以 f64 为键的记录，按 `total_cmp` 排序
+#[derive(Clone, Copy, PartialEq)]
+struct Record {
+    key: f64,
+    id: u32,
+}
+
+impl Eq for Record {}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Record {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.total_cmp(&other.key)
+    }
+}
+
+fn main() {
+    let integers = random_vec(LEN, 42);
+    let records: Vec<Record> = random_vec(LEN, 7)
+        .into_iter()
+        .enumerate()
+        .map(|(id, x)| Record {
+            key: (x >> 11) as f64 / (1u64 << 53) as f64,
+            id: id as u32,
+        })
+        .collect();
+
+    println!("{:<12} {:<18} {:>12}", "input", "algorithm", "time (ms)");
+    bench("random u64", &integers);
+    bench("f64 keyed", &records);
+}
+
+fn bench<T: Ord + Clone>(input_name: &str, input: &[T]) {
+    let branchy = measure(input, ROUNDS, quick_sort);
+    let block = measure(input, ROUNDS, block_quick_sort);
+    println!(
+        "{:<12} {:<18} {:>12.3}",
+        input_name,
+        "quick_sort",
+        branchy.as_secs_f64() * 1000.0
+    );
+    println!(
+        "{:<12} {:<18} {:>12.3} ({:.2}x)",
+        input_name,
+        "block_quick_sort",
+        block.as_secs_f64() * 1000.0,
+        branchy.as_secs_f64() / block.as_secs_f64()
+    );
+}
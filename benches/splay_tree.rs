@@ -0,0 +1,132 @@
+//! 在均匀分布与 Zipf 分布的查询下比较 `SplayTree` 与 `BinarySearchTree`
+//!
+//! 运行：`cargo bench --bench splay_tree`
+
+mod common;
+
+use common::{comparisons_in, random_vec, Counted, XorShift};
+use rust_demo::data_structures::{BinarySearchTree, SplayTree};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const KEYS: usize = 100_000;
+const QUERIES: usize = 1_000_000;
+const ROUNDS: usize = 5;
+
+fn main() {
+    // 按随机顺序插入，避免二叉搜索树退化
+    let keys = random_vec(KEYS, 42);
+
+    println!(
+        "{:<12} {:<18} {:>12} {:>18}",
+        "queries", "tree", "time (ms)", "comparisons/query"
+    );
+    for (name, queries) in [
+        ("uniform", uniform_queries(&keys, 7)),
+        ("zipf s=1.0", zipf_queries(&keys, 1.0, 7)),
+        ("zipf s=1.5", zipf_queries(&keys, 1.5, 7)),
+    ] {
+        bench_bst(name, &keys, &queries);
+        bench_splay(name, &keys, &queries);
+    }
+}
+
+fn uniform_queries(keys: &[u64], seed: u64) -> Vec<u64> {
+    let mut rng = XorShift::new(seed);
+    (0..QUERIES)
+        .map(|_| keys[(rng.next_u64() % keys.len() as u64) as usize])
+        .collect()
+}
+
+/// 第 k 热的键被查询的概率正比于 1 / k^s
+///
+/// 热度顺序与插入顺序无关，否则最热的键恰好是二叉搜索树的根节点。
+fn zipf_queries(keys: &[u64], s: f64, seed: u64) -> Vec<u64> {
+    let mut rng = XorShift::new(seed);
+    let mut hot = keys.to_vec();
+    for i in (1..hot.len()).rev() {
+        hot.swap(i, (rng.next_u64() % (i as u64 + 1)) as usize);
+    }
+
+    let mut cumulative = Vec::with_capacity(keys.len());
+    let mut total = 0.0;
+    for k in 1..=keys.len() {
+        total += 1.0 / (k as f64).powf(s);
+        cumulative.push(total);
+    }
+
+    (0..QUERIES)
+        .map(|_| {
+            let target = (rng.next_u64() as f64 / u64::MAX as f64) * total;
+            let rank = cumulative.partition_point(|it| *it < target);
+            hot[rank.min(hot.len() - 1)]
+        })
+        .collect()
+}
+
+/// 多次运行查询，返回单次运行的最短耗时
+fn measure(rounds: usize, mut run: impl FnMut()) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..rounds {
+        let start = Instant::now();
+        run();
+        best = best.min(start.elapsed());
+    }
+    best
+}
+
+fn bench_bst(input_name: &str, keys: &[u64], queries: &[u64]) {
+    let mut tree = BinarySearchTree::new();
+    for key in keys {
+        tree.insert(*key);
+    }
+    let time = measure(ROUNDS, || {
+        for query in queries {
+            black_box(tree.search(query));
+        }
+    });
+
+    let mut tree = BinarySearchTree::new();
+    for key in keys {
+        tree.insert(Counted(*key));
+    }
+    let comparisons = comparisons_in(|| {
+        for query in queries {
+            black_box(tree.search(&Counted(*query)));
+        }
+    });
+    report(input_name, "BinarySearchTree", time, comparisons);
+}
+
+fn bench_splay(input_name: &str, keys: &[u64], queries: &[u64]) {
+    let mut tree = SplayTree::new();
+    for key in keys {
+        tree.insert(*key);
+    }
+    let time = measure(ROUNDS, || {
+        for query in queries {
+            black_box(tree.search(query));
+        }
+    });
+
+    let mut tree = SplayTree::new();
+    for key in keys {
+        tree.insert(Counted(*key));
+    }
+    let comparisons = comparisons_in(|| {
+        for query in queries {
+            black_box(tree.search(&Counted(*query)));
+        }
+    });
+    report(input_name, "SplayTree", time, comparisons);
+}
+
+fn report(input_name: &str, tree: &str, time: Duration, comparisons: u64) {
+    println!(
+        "{:<12} {:<18} {:>12.3} {:>18.2}",
+        input_name,
+        tree,
+        time.as_secs_f64() * 1000.0,
+        comparisons as f64 / QUERIES as f64
+    );
+}
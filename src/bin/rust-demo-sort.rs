@@ -0,0 +1,728 @@
+//! 基于 `rust_demo::sort` 的命令行排序工具
+//!
+//! 从标准输入或文件读取数字、文本行或 CSV 记录，使用指定的排序算法排序后输出，
+//! 用法与 GNU `sort` 相近，便于观察和比较各个排序算法。
+
+use std::cmp::Ordering;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::time::{Duration, Instant};
+
+use rust_demo::sort;
+
+const USAGE: &str = "\
+用法: rust-demo-sort [选项]... [文件]...
+
+将所有文件（未指定或为 - 时读取标准输入）的行排序后输出到标准输出。
+
+选项:
+  -a, --algorithm NAME   使用的排序算法，默认为 quick，指定 --stable 时默认为 td-merge
+  -n, --numeric          按键开头的十进制数排序，不识别指数，非数字按 0 处理
+  -r, --reverse          逆序输出
+  -u, --unique           键相同的行只输出输入中的第一行
+  -s, --stable           保持键相同的行的输入顺序，只能使用稳定的排序算法
+  -k, --key N[,M]        以第 N 个字段开始、第 M 个字段（未指定时为行尾）结束的部分作为键，
+                         字段编号从 1 开始，默认使用整行
+  -t, --separator SEP    字段分隔符，默认为连续的空白字符
+      --csv              按 CSV 格式解析字段（分隔符为逗号，支持双引号）
+      --header           第一行为表头，不参与排序
+      --stats            排序完成后向标准错误输出比较次数和耗时
+  -h, --help             显示帮助信息
+
+排序算法:
+  bubble, insertion, selection, shell, heap, bottom-up-heap, smooth, quick,
+  block-quick, td-merge, bu-merge, cycle, sample, parallel-sample
+
+与 GNU sort 的差异:
+  - 只能指定一个 -k，不支持字符位置（N.C）和字段后的选项（如 -k2n）
+  - 未指定 -t 时字段开头的空白不属于键，相当于总是指定了 -b
+  - 文本按字节比较，-n 不识别千位分隔符，相当于 LC_ALL=C
+  - -a、--csv、--header 和 --stats 是本工具特有的选项
+";
+
+/// 稳定的排序算法
+const STABLE_ALGORITHMS: [&str; 4] = ["bubble", "insertion", "td-merge", "bu-merge"];
+
+const ALGORITHMS: [&str; 14] = [
+    "bubble",
+    "insertion",
+    "selection",
+    "shell",
+    "heap",
+    "bottom-up-heap",
+    "smooth",
+    "quick",
+    "block-quick",
+    "td-merge",
+    "bu-merge",
+    "cycle",
+    "sample",
+    "parallel-sample",
+];
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    algorithm: Option<String>,
+    numeric: bool,
+    reverse: bool,
+    unique: bool,
+    stable: bool,
+    key: Option<KeyRange>,
+    separator: Option<String>,
+    csv: bool,
+    header: bool,
+    stats: bool,
+    help: bool,
+    files: Vec<String>,
+}
+
+impl Options {
+    fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                options.files.extend(args.by_ref());
+                break;
+            }
+
+            if let Some(long) = arg.strip_prefix("--") {
+                let (name, inline) = match long.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (long, None),
+                };
+                let mut value = |name: &str| {
+                    inline
+                        .clone()
+                        .or_else(|| args.next())
+                        .ok_or(format!("选项 --{} 需要一个参数", name))
+                };
+                match name {
+                    "algorithm" => options.algorithm = Some(value(name)?),
+                    "key" => options.key = Some(parse_key(&value(name)?)?),
+                    "separator" => options.separator = Some(value(name)?),
+                    "numeric" => options.numeric = true,
+                    "reverse" => options.reverse = true,
+                    "unique" => options.unique = true,
+                    "stable" => options.stable = true,
+                    "csv" => options.csv = true,
+                    "header" => options.header = true,
+                    "stats" => options.stats = true,
+                    "help" => options.help = true,
+                    _ => return Err(format!("未知选项 --{}", name)),
+                }
+            } else if arg.len() > 1 && arg.starts_with('-') {
+                // 短选项可以合并，例如 -nru；带参数的短选项可以紧跟参数，例如 -k2
+                let flags: Vec<char> = arg[1..].chars().collect();
+                for (idx, flag) in flags.iter().enumerate() {
+                    let rest: String = flags[idx + 1..].iter().collect();
+                    let mut value = || {
+                        if rest.is_empty() {
+                            args.next().ok_or(format!("选项 -{} 需要一个参数", flag))
+                        } else {
+                            Ok(rest.clone())
+                        }
+                    };
+                    match flag {
+                        'a' => options.algorithm = Some(value()?),
+                        'k' => options.key = Some(parse_key(&value()?)?),
+                        't' => options.separator = Some(value()?),
+                        'n' => options.numeric = true,
+                        'r' => options.reverse = true,
+                        'u' => options.unique = true,
+                        's' => options.stable = true,
+                        'h' => options.help = true,
+                        _ => return Err(format!("未知选项 -{}", flag)),
+                    }
+                    if matches!(flag, 'a' | 'k' | 't') {
+                        break;
+                    }
+                }
+            } else {
+                options.files.push(arg);
+            }
+        }
+
+        if let Some(algorithm) = &options.algorithm {
+            if !ALGORITHMS.contains(&algorithm.as_str()) {
+                return Err(format!("未知的排序算法 {}", algorithm));
+            }
+            if options.stable && !STABLE_ALGORITHMS.contains(&algorithm.as_str()) {
+                return Err(format!(
+                    "{} 不是稳定的排序算法，--stable 只能使用 {}",
+                    algorithm,
+                    STABLE_ALGORITHMS.join(", ")
+                ));
+            }
+        }
+        if options.csv && options.separator.is_some() {
+            return Err(String::from("--csv 不能与 --separator 同时使用"));
+        }
+        Ok(options)
+    }
+
+    fn algorithm(&self) -> &str {
+        match &self.algorithm {
+            Some(algorithm) => algorithm,
+            None if self.stable => "td-merge",
+            None => "quick",
+        }
+    }
+}
+
+/// -k 指定的字段范围，end 为 None 时到行尾结束
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct KeyRange {
+    start: usize,
+    end: Option<usize>,
+}
+
+fn parse_key(value: &str) -> Result<KeyRange, String> {
+    let field = |field: &str| match field.parse::<usize>() {
+        Ok(field) if field > 0 => Ok(field),
+        _ => Err(format!("无效的字段编号 {}，字段编号从 1 开始", field)),
+    };
+    match value.split_once(',') {
+        Some((start, end)) => Ok(KeyRange {
+            start: field(start)?,
+            end: Some(field(end)?),
+        }),
+        None => Ok(KeyRange {
+            start: field(value)?,
+            end: None,
+        }),
+    }
+}
+
+/// 行的排序键
+#[derive(Debug)]
+enum Key {
+    Text(String),
+    Number(f64),
+}
+
+/// 键相等的行之间的比较方式
+#[derive(Debug, PartialEq)]
+enum Tiebreak {
+    /// 视为相等，由稳定的排序算法保持输入顺序
+    None,
+    /// 按输入中的位置比较，去重时保留输入中的第一行
+    Index,
+    /// 按整行比较，使不稳定的排序算法也能得到确定的输出
+    Text,
+}
+
+/// 行之间的比较方式
+struct Order {
+    reverse: bool,
+    tiebreak: Tiebreak,
+    /// 行之间的比较次数，并行排序时会在多个线程中累加
+    comparisons: AtomicU64,
+}
+
+impl Order {
+    fn new(options: &Options) -> Self {
+        let tiebreak = if options.unique {
+            Tiebreak::Index
+        } else if options.stable {
+            Tiebreak::None
+        } else {
+            Tiebreak::Text
+        };
+        Order {
+            reverse: options.reverse,
+            tiebreak,
+            comparisons: AtomicU64::new(0),
+        }
+    }
+}
+
+struct Line<'a> {
+    text: &'a str,
+    key: Key,
+    /// 输入中的位置，用于去重时保留第一行以及按排序结果输出
+    index: usize,
+    order: &'a Order,
+}
+
+impl Line<'_> {
+    fn cmp_key(&self, other: &Self) -> Ordering {
+        let ordering = match (&self.key, &other.key) {
+            (Key::Number(x), Key::Number(y)) => x.total_cmp(y),
+            (Key::Text(x), Key::Text(y)) => x.as_bytes().cmp(y.as_bytes()),
+            (Key::Number(_), Key::Text(_)) => Ordering::Less,
+            (Key::Text(_), Key::Number(_)) => Ordering::Greater,
+        };
+        if self.order.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+impl PartialEq for Line<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Line<'_> {}
+
+impl PartialOrd for Line<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Line<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.order.comparisons.fetch_add(1, AtomicOrdering::Relaxed);
+        let ordering = self.cmp_key(other);
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        match self.order.tiebreak {
+            Tiebreak::None => ordering,
+            // 逆序输出时也保留输入中的第一行
+            Tiebreak::Index => self.index.cmp(&other.index),
+            Tiebreak::Text if self.order.reverse => other.text.as_bytes().cmp(self.text.as_bytes()),
+            Tiebreak::Text => self.text.as_bytes().cmp(other.text.as_bytes()),
+        }
+    }
+}
+
+/// 按选项从一行中提取排序键，键包括范围内各字段之间的分隔符，起始字段不存在时为空字符串
+fn extract_key(line: &str, options: &Options) -> String {
+    let Some(KeyRange { start, end }) = options.key else {
+        return line.to_string();
+    };
+    if end.is_some_and(|end| end < start) {
+        return String::new();
+    }
+
+    if options.csv {
+        let fields = split_csv(line);
+        let end = end.unwrap_or(fields.len()).min(fields.len());
+        return fields
+            .get(start - 1..end)
+            .map(|fields| fields.join(","))
+            .unwrap_or_default();
+    }
+    let spans = field_spans(line, options.separator.as_deref());
+    let Some(&(from, _)) = spans.get(start - 1) else {
+        return String::new();
+    };
+    let to = match end.and_then(|end| spans.get(end - 1)) {
+        Some(&(_, to)) => to,
+        None => line.len(),
+    };
+    line[from..to].to_string()
+}
+
+/// 拆分一行中的字段，返回每个字段的起止位置
+///
+/// 未指定分隔符时字段为连续的非空白字符，字段开头的空白不属于字段。
+fn field_spans(line: &str, separator: Option<&str>) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    if let Some(separator) = separator {
+        let mut from = 0;
+        for field in line.split(separator) {
+            spans.push((from, from + field.len()));
+            from += field.len() + separator.len();
+        }
+        return spans;
+    }
+
+    let mut from = None;
+    for (idx, c) in line.char_indices() {
+        match (c.is_whitespace(), from) {
+            (true, Some(start)) => {
+                spans.push((start, idx));
+                from = None;
+            }
+            (false, None) => from = Some(idx),
+            _ => {}
+        }
+    }
+    if let Some(start) = from {
+        spans.push((start, line.len()));
+    }
+    spans
+}
+
+/// 按 CSV 格式拆分一行，双引号内的逗号不作为分隔符，两个连续的双引号表示一个双引号
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// 解析字段开头的数值，不是数字时按 0 处理
+///
+/// 与 GNU sort 的 -n 相同，只识别形如 `[+-]digits[.digits]` 的最长前缀，不识别指数。
+fn parse_number(field: &str) -> f64 {
+    let field = field.trim();
+    let bytes = field.as_bytes();
+    let digits = |from: usize| {
+        bytes[from.min(bytes.len())..]
+            .iter()
+            .take_while(|it| it.is_ascii_digit())
+            .count()
+    };
+
+    let mut end = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let int_digits = digits(end);
+    end += int_digits;
+    let frac_digits = if bytes.get(end) == Some(&b'.') {
+        digits(end + 1)
+    } else {
+        0
+    };
+    if int_digits + frac_digits == 0 {
+        return 0.0;
+    }
+    if bytes.get(end) == Some(&b'.') {
+        end += 1 + frac_digits;
+    }
+    field[..end].parse().unwrap_or(0.0)
+}
+
+fn read_lines(files: &[String]) -> Result<Vec<String>, String> {
+    let mut lines = Vec::new();
+    let stdin = [String::from("-")];
+    let files = if files.is_empty() { &stdin[..] } else { files };
+    for file in files {
+        let reader: Box<dyn BufRead> = if file == "-" {
+            Box::new(io::stdin().lock())
+        } else {
+            let file = File::open(file).map_err(|err| format!("无法打开 {}: {}", file, err))?;
+            Box::new(BufReader::new(file))
+        };
+        for line in reader.lines() {
+            lines.push(line.map_err(|err| format!("读取 {} 失败: {}", file, err))?);
+        }
+    }
+    Ok(lines)
+}
+
+/// 使用指定算法排序
+fn sort_with(algorithm: &str, arr: &mut [&Line]) {
+    match algorithm {
+        "bubble" => sort::bubble_sort(arr),
+        "insertion" => sort::insertion_sort(arr),
+        "selection" => sort::selection_sort(arr),
+        "shell" => sort::shell_sort(arr),
+        "heap" => sort::heap_sort(arr),
+        "bottom-up-heap" => sort::bottom_up_heap_sort(arr),
+        "smooth" => sort::smooth_sort(arr),
+        "quick" => sort::quick_sort(arr),
+        "block-quick" => sort::block_quick_sort(arr),
+        "td-merge" => sort::td_merge_sort(arr),
+        "bu-merge" => sort::bu_merge_sort(arr),
+        "cycle" => sort::cycle_sort(arr),
+        "sample" => sort::sample_sort(arr),
+        "parallel-sample" => sort::parallel_sample_sort(arr),
+        _ => unreachable!("algorithm is validated when parsing options"),
+    }
+}
+
+/// 排序过程的统计信息
+struct Stats {
+    comparisons: u64,
+    elapsed: Duration,
+}
+
+impl Stats {
+    /// 按 --stats 的输出格式生成统计信息
+    fn report(&self) -> String {
+        format!(
+            "comparisons: {}\ntime:        {:.3} ms\n",
+            self.comparisons,
+            self.elapsed.as_secs_f64() * 1000.0
+        )
+    }
+}
+
+/// 按选项排序输入的所有行，返回按输出顺序排列的行（包括表头）和统计信息
+fn sort_lines<'a>(texts: &'a [String], options: &Options) -> (Vec<&'a str>, Stats) {
+    let (header, texts) = match texts.split_first() {
+        Some((header, rest)) if options.header => (Some(header.as_str()), rest),
+        _ => (None, texts),
+    };
+
+    let order = Order::new(options);
+    let lines: Vec<Line> = texts
+        .iter()
+        .enumerate()
+        .map(|(index, text)| {
+            let field = extract_key(text, options);
+            let key = if options.numeric {
+                Key::Number(parse_number(&field))
+            } else {
+                Key::Text(field)
+            };
+            Line {
+                text,
+                key,
+                index,
+                order: &order,
+            }
+        })
+        .collect();
+
+    let mut sorted: Vec<&Line> = lines.iter().collect();
+    let start = Instant::now();
+    sort_with(options.algorithm(), &mut sorted);
+    let elapsed = start.elapsed();
+
+    if options.unique {
+        // 键相等的行按输入中的位置排列，保留每组中的第一行
+        sorted.dedup_by(|a, b| a.cmp_key(b) == Ordering::Equal);
+    }
+
+    let output = header
+        .into_iter()
+        .chain(sorted.iter().map(|line| texts[line.index].as_str()))
+        .collect();
+    let stats = Stats {
+        comparisons: order.comparisons.load(AtomicOrdering::Relaxed),
+        elapsed,
+    };
+    (output, stats)
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let texts = read_lines(&options.files)?;
+    let (output, stats) = sort_lines(&texts, options);
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let write_err = |err: io::Error| format!("写入标准输出失败: {}", err);
+    for line in &output {
+        writeln!(out, "{}", line).map_err(write_err)?;
+    }
+    out.flush().map_err(write_err)?;
+
+    if options.stats {
+        eprint!("{}", stats.report());
+    }
+    Ok(())
+}
+
+fn main() {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("rust-demo-sort: {}", err);
+            eprintln!("使用 --help 查看帮助信息");
+            process::exit(2);
+        }
+    };
+
+    if options.help {
+        print!("{}", USAGE);
+        return;
+    }
+
+    if let Err(err) = run(&options) {
+        eprintln!("rust-demo-sort: {}", err);
+        process::exit(2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|it| it.to_string()))
+    }
+
+    fn sorted(input: &[&str], args: &[&str]) -> Vec<String> {
+        let options = parse(args).unwrap();
+        let texts: Vec<String> = input.iter().map(|it| it.to_string()).collect();
+        let (output, _) = sort_lines(&texts, &options);
+        output.into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_options() {
+        let options = parse(&["-nru", "-k2", "-t", ",", "--algorithm=heap", "a.txt"]).unwrap();
+        assert!(options.numeric && options.reverse && options.unique);
+        assert_eq!(
+            options.key,
+            Some(KeyRange {
+                start: 2,
+                end: None
+            })
+        );
+        assert_eq!(options.separator.as_deref(), Some(","));
+        assert_eq!(options.algorithm(), "heap");
+        assert_eq!(options.files, ["a.txt"]);
+
+        assert_eq!(
+            parse(&["--key=2,3"]).unwrap().key,
+            Some(KeyRange {
+                start: 2,
+                end: Some(3)
+            })
+        );
+        assert_eq!(parse(&["-s"]).unwrap().algorithm(), "td-merge");
+        assert_eq!(parse(&[]).unwrap().algorithm(), "quick");
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert!(parse(&["-x"]).is_err());
+        assert!(parse(&["-k", "0"]).is_err());
+        assert!(parse(&["-k"]).is_err());
+        assert!(parse(&["-k", "1,0"]).is_err());
+        assert!(parse(&["-k", "1,"]).is_err());
+        assert!(parse(&["-k", ",2"]).is_err());
+        assert!(parse(&["-k", "2.1"]).is_err());
+        assert!(parse(&["-a", "bogo"]).is_err());
+        assert!(parse(&["-s", "-a", "quick"]).is_err());
+        assert!(parse(&["--csv", "-t", ";"]).is_err());
+    }
+
+    #[test]
+    fn numeric_and_reverse() {
+        let input = ["10", "9", "-1.5", "abc", "2e1"];
+        assert_eq!(sorted(&input, &["-n"]), ["-1.5", "abc", "2e1", "9", "10"]);
+        assert_eq!(sorted(&input, &["-nr"]), ["10", "9", "2e1", "abc", "-1.5"]);
+        assert_eq!(sorted(&input, &[]), ["-1.5", "10", "2e1", "9", "abc"]);
+    }
+
+    #[test]
+    fn key_column() {
+        let input = ["b 3", "a 1", "c 2"];
+        assert_eq!(sorted(&input, &["-k", "2", "-n"]), ["a 1", "c 2", "b 3"]);
+
+        // 未指定结束字段时键到行尾结束
+        let input = ["b 2 x", "b  1 y", "a 2 z"];
+        assert_eq!(sorted(&input, &["-k2"]), ["b  1 y", "b 2 x", "a 2 z"]);
+        assert_eq!(sorted(&input, &["-k2,2"]), ["b  1 y", "a 2 z", "b 2 x"]);
+        assert_eq!(sorted(&input, &["-k1,2"]), ["a 2 z", "b  1 y", "b 2 x"]);
+        assert_eq!(sorted(&input, &["-k3,2"]), ["a 2 z", "b  1 y", "b 2 x"]);
+        assert_eq!(
+            sorted(&["x,b,2", "x,a,3", "y"], &["-t", ",", "-k2"]),
+            ["y", "x,a,3", "x,b,2"]
+        );
+        assert_eq!(field_spans("  a  bc ", None), [(2, 3), (5, 7)]);
+        assert_eq!(field_spans("a,,b", Some(",")), [(0, 1), (2, 2), (3, 4)]);
+
+        let csv = ["\"x,y\",3", "z,1", "\"w\"\"\",2"];
+        assert_eq!(
+            sorted(&csv, &["--csv", "-k1"]),
+            ["\"w\"\"\",2", "\"x,y\",3", "z,1"]
+        );
+        assert_eq!(
+            sorted(&["a,\"x\",2", "b,x,1"], &["--csv", "-k2"]),
+            ["b,x,1", "a,\"x\",2"]
+        );
+        assert_eq!(split_csv("\"x,y\",3"), ["x,y", "3"]);
+    }
+
+    #[test]
+    fn stable() {
+        let input = ["b 1", "a 1", "c 0", "d 1"];
+        for algorithm in STABLE_ALGORITHMS {
+            assert_eq!(
+                sorted(&input, &["-s", "-k2", "-a", algorithm]),
+                ["c 0", "b 1", "a 1", "d 1"]
+            );
+        }
+    }
+
+    #[test]
+    fn every_algorithm() {
+        let input = ["3", "1", "2", "1", "10"];
+        for algorithm in ALGORITHMS {
+            assert_eq!(
+                sorted(&input, &["-n", "-a", algorithm]),
+                ["1", "1", "2", "3", "10"]
+            );
+        }
+    }
+
+    #[test]
+    fn unique() {
+        let input = ["b 1", "a 1", "c 0", "d 1", "e 0"];
+        for algorithm in ALGORITHMS {
+            assert_eq!(
+                sorted(&input, &["-u", "-k2", "-a", algorithm]),
+                ["c 0", "b 1"]
+            );
+            assert_eq!(
+                sorted(&input, &["-ur", "-k2", "-a", algorithm]),
+                ["b 1", "c 0"]
+            );
+        }
+        assert_eq!(sorted(&input, &["-us", "-k2"]), ["c 0", "b 1"]);
+        assert_eq!(sorted(&["2", "02", "1", "2"], &["-un"]), ["1", "2"]);
+    }
+
+    #[test]
+    fn header() {
+        let input = ["name,age", "bob,30", "alice,25"];
+        assert_eq!(
+            sorted(&input, &["--header", "--csv", "-k2", "-n"]),
+            ["name,age", "alice,25", "bob,30"]
+        );
+        assert_eq!(sorted(&input, &[]), ["alice,25", "bob,30", "name,age"]);
+        assert_eq!(sorted(&["name"], &["--header"]), ["name"]);
+        assert!(sorted(&[], &["--header"]).is_empty());
+    }
+
+    #[test]
+    fn stats() {
+        let texts: Vec<String> = ["h", "3", "1", "2"]
+            .iter()
+            .map(|it| it.to_string())
+            .collect();
+
+        let options = parse(&["--stats", "--header", "-a", "selection"]).unwrap();
+        let (_, stats) = sort_lines(&texts, &options);
+        assert_eq!(stats.comparisons, 3);
+        let report = stats.report();
+        assert!(report.starts_with("comparisons: 3\ntime:        "));
+        assert!(report.ends_with(" ms\n"));
+        assert_eq!(report.lines().count(), 2);
+    }
+
+    #[test]
+    fn numeric_prefix() {
+        assert_eq!(parse_number(" 12abc"), 12.0);
+        assert_eq!(parse_number("-1.5e2x"), -1.5);
+        assert_eq!(parse_number("2e1"), 2.0);
+        assert_eq!(parse_number("3e"), 3.0);
+        assert_eq!(parse_number("3e+"), 3.0);
+        assert_eq!(parse_number(".5"), 0.5);
+        assert_eq!(parse_number("7."), 7.0);
+        assert_eq!(parse_number("+."), 0.0);
+        assert_eq!(parse_number("nan"), 0.0);
+        assert_eq!(parse_number("数字"), 0.0);
+        assert_eq!(parse_number(""), 0.0);
+    }
+}
@@ -0,0 +1,662 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::mem;
+
+struct Node<T>
+where
+    T: PartialOrd,
+{
+    val: T,
+    left: Link<T>,
+    right: Link<T>,
+    /// 以该节点为根的子树的高度，叶子节点的高度为 1
+    height: usize,
+}
+
+impl<T> Node<T>
+where
+    T: PartialOrd,
+{
+    fn new(val: T) -> Self {
+        Self {
+            val,
+            left: None,
+            right: None,
+            height: 1,
+        }
+    }
+
+    fn update(&mut self) {
+        self.height = height(&self.left).max(height(&self.right)) + 1;
+    }
+
+    /// 平衡因子，左子树与右子树的高度差
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+fn height<T>(link: &Link<T>) -> usize
+where
+    T: PartialOrd,
+{
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+/// 右旋，左子节点成为新的根节点
+fn rotate_right<T>(mut node: Box<Node<T>>) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    node.update();
+    left.right = Some(node);
+    left.update();
+    left
+}
+
+/// 左旋，右子节点成为新的根节点
+fn rotate_left<T>(mut node: Box<Node<T>>) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    node.update();
+    right.left = Some(node);
+    right.update();
+    right
+}
+
+/// 恢复子树根节点的平衡，要求左右子树都已平衡且高度差不超过 2
+fn rebalance<T>(link: &mut Link<T>)
+where
+    T: PartialOrd,
+{
+    let Some(mut node) = link.take() else {
+        return;
+    };
+
+    node.update();
+    let factor = node.balance_factor();
+    if factor > 1 {
+        // 左右情况先将左子节点左旋，转换为左左情况
+        if node.left.as_ref().unwrap().balance_factor() < 0 {
+            node.left = node.left.take().map(rotate_left);
+        }
+        node = rotate_right(node);
+    } else if factor < -1 {
+        if node.right.as_ref().unwrap().balance_factor() > 0 {
+            node.right = node.right.take().map(rotate_right);
+        }
+        node = rotate_left(node);
+    }
+    *link = Some(node);
+}
+
+fn insert<T>(link: &mut Link<T>, val: T)
+where
+    T: PartialOrd,
+{
+    match link {
+        None => *link = Some(Box::new(Node::new(val))),
+        Some(node) => {
+            if val < node.val {
+                insert(&mut node.left, val);
+            } else {
+                insert(&mut node.right, val);
+            }
+            rebalance(link);
+        }
+    }
+}
+
+fn remove<T>(link: &mut Link<T>, val: &T) -> Option<T>
+where
+    T: PartialOrd,
+{
+    let node = link.as_mut()?;
+    let removed = match val.partial_cmp(&node.val)? {
+        Ordering::Less => remove(&mut node.left, val),
+        Ordering::Greater => remove(&mut node.right, val),
+        Ordering::Equal => delete(link),
+    };
+    rebalance(link);
+    removed
+}
+
+/// 删除子树的根节点，有两个子节点时用后继节点替换
+fn delete<T>(link: &mut Link<T>) -> Option<T>
+where
+    T: PartialOrd,
+{
+    let mut node = link.take()?;
+    match (node.left.take(), node.right.take()) {
+        (None, child) | (child, None) => {
+            *link = child;
+            Some(node.val)
+        }
+        (left, mut right) => {
+            let successor = pop_min(&mut right).unwrap();
+            let val = mem::replace(&mut node.val, successor);
+            node.left = left;
+            node.right = right;
+            *link = Some(node);
+            rebalance(link);
+            Some(val)
+        }
+    }
+}
+
+fn pop_min<T>(link: &mut Link<T>) -> Option<T>
+where
+    T: PartialOrd,
+{
+    let node = link.as_mut()?;
+    if node.left.is_none() {
+        return delete(link);
+    }
+    let min = pop_min(&mut node.left);
+    rebalance(link);
+    min
+}
+
+fn pop_max<T>(link: &mut Link<T>) -> Option<T>
+where
+    T: PartialOrd,
+{
+    let node = link.as_mut()?;
+    if node.right.is_none() {
+        return delete(link);
+    }
+    let max = pop_max(&mut node.right);
+    rebalance(link);
+    max
+}
+
+/// AVL 树
+///
+/// 自平衡的二叉搜索树，任意节点左右子树的高度差不超过 1，树的高度不超过 1.44logn，
+/// 插入、删除和查询的时间复杂度均为 O(logn)。插入和删除后自底向上通过旋转恢复平衡。
+pub struct AvlTree<T>
+where
+    T: PartialOrd,
+{
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T> AvlTree<T>
+where
+    T: PartialOrd,
+{
+    /// 创建一个空的 AVL 树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let tree: AvlTree<i32> = AvlTree::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// 获取元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 判断 AVL 树是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let tree: AvlTree<i32> = AvlTree::new();
+    ///
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 获取树的高度，空树的高度为 0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// for val in 0..7 {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// 向 AVL 树添加一个元素，时间复杂度为 O(logn)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    ///
+    /// tree.insert(1);
+    /// ```
+    pub fn insert(&mut self, val: T) {
+        insert(&mut self.root, val);
+        self.len += 1;
+    }
+
+    /// 删除一个等于指定值的元素，返回被删除的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.remove(&1), Some(1));
+    /// assert_eq!(tree.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, val: &T) -> Option<T> {
+        let removed = remove(&mut self.root, val);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// 删除并返回最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_min(), Some(1));
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        let min = pop_min(&mut self.root)?;
+        self.len -= 1;
+        Some(min)
+    }
+
+    /// 删除并返回最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_max(), Some(2));
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        let max = pop_max(&mut self.root)?;
+        self.len -= 1;
+        Some(max)
+    }
+
+    /// 查询 AVL 树中是否存在指定值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert!(tree.search(&1));
+    /// assert!(!tree.search(&2));
+    /// ```
+    pub fn search(&self, val: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match val.partial_cmp(&node.val) {
+                Some(Ordering::Less) => link = &node.left,
+                Some(Ordering::Greater) => link = &node.right,
+                Some(Ordering::Equal) => return true,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// 获取最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取不大于指定值的最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.floor(&2), Some(&1));
+    /// assert_eq!(tree.floor(&0), None);
+    /// ```
+    pub fn floor(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Greater => link = &node.left,
+                Ordering::Less => {
+                    result = Some(&node.val);
+                    link = &node.right;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取不小于指定值的最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.ceil(&2), Some(&3));
+    /// assert_eq!(tree.ceil(&4), None);
+    /// ```
+    pub fn ceil(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Less => link = &node.right,
+                Ordering::Greater => {
+                    result = Some(&node.val);
+                    link = &node.left;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取按从小到大的顺序迭代元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::AvlTree;
+    /// let mut tree = AvlTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            len: self.len,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T> Default for AvlTree<T>
+where
+    T: PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a AvlTree<T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// 中序遍历的迭代器
+pub struct Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    stack: Vec<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.len -= 1;
+        Some(&node.val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> where T: PartialOrd {}
+
+impl<T> FusedIterator for Iter<'_, T> where T: PartialOrd {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::random_vec;
+    use alloc::vec::Vec;
+
+    /// 检查子树的顺序、高度和平衡因子，返回子树的节点数量
+    fn check<T>(link: &Link<T>, lo: Option<&T>, hi: Option<&T>) -> usize
+    where
+        T: PartialOrd,
+    {
+        let Some(node) = link else {
+            return 0;
+        };
+        assert!(lo.is_none_or(|lo| *lo <= node.val));
+        assert!(hi.is_none_or(|hi| node.val <= *hi));
+        assert_eq!(node.height, height(&node.left).max(height(&node.right)) + 1);
+        assert!(node.balance_factor().abs() <= 1);
+        check(&node.left, lo, Some(&node.val)) + check(&node.right, Some(&node.val), hi) + 1
+    }
+
+    fn prepare_tree() -> AvlTree<i32> {
+        let mut result = AvlTree::new();
+        for val in [1, 2, 3, 5, 6, 7] {
+            result.insert(val);
+        }
+        result
+    }
+
+    #[test]
+    fn empty() {
+        let mut tree: AvlTree<i32> = AvlTree::new();
+        assert!(tree.is_empty());
+        assert!(!tree.search(&1));
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.floor(&1), None);
+        assert_eq!(tree.remove(&1), None);
+        assert_eq!(tree.pop_max(), None);
+        assert_eq!(tree.height(), 0);
+    }
+
+    #[test]
+    fn search_and_bounds() {
+        let tree = prepare_tree();
+        assert!(tree.search(&1));
+        assert!(tree.search(&7));
+        assert!(!tree.search(&4));
+        assert_eq!(tree.max(), Some(&7));
+        assert_eq!(tree.min(), Some(&1));
+        assert_eq!(tree.floor(&4), Some(&3));
+        assert_eq!(tree.ceil(&4), Some(&5));
+        assert_eq!(tree.floor(&5), Some(&5));
+        assert_eq!(tree.ceil(&8), None);
+    }
+
+    #[test]
+    fn remove() {
+        let mut tree = prepare_tree();
+        assert_eq!(tree.remove(&4), None);
+        assert_eq!(tree.remove(&2), Some(2));
+        assert_eq!(tree.pop_min(), Some(1));
+        assert_eq!(tree.pop_max(), Some(7));
+        assert_eq!(check(&tree.root, None, None), 3);
+        assert!(tree.iter().eq(&[3, 5, 6]));
+        assert_eq!(tree.len(), 3);
+    }
+
+    #[test]
+    fn repeat() {
+        let mut tree = AvlTree::new();
+        for val in [2, 2, 1, 2, 3, 2] {
+            tree.insert(val);
+        }
+        check(&tree.root, None, None);
+        assert_eq!(tree.remove(&2), Some(2));
+        assert_eq!(tree.remove(&2), Some(2));
+        assert!(tree.iter().eq(&[1, 2, 2, 3]));
+        check(&tree.root, None, None);
+    }
+
+    #[test]
+    fn sorted_insert() {
+        // 有序插入时普通二叉搜索树退化为链表，AVL 树的高度仍为 O(logn)
+        let n = 200_000;
+        let mut tree = AvlTree::new();
+        for val in 0..n {
+            tree.insert(val);
+        }
+        assert_eq!(tree.len(), n);
+        assert!(tree.height() <= 18);
+        assert!(tree.iter().copied().eq(0..n));
+
+        for val in (0..n).step_by(2) {
+            assert_eq!(tree.remove(&val), Some(val));
+        }
+        assert_eq!(check(&tree.root, None, None), n / 2);
+    }
+
+    #[test]
+    fn random() {
+        let mut tree = AvlTree::new();
+        let mut expected = Vec::new();
+        let ops = random_vec(3000, 50);
+        for (idx, val) in random_vec(3000, 51).into_iter().enumerate() {
+            let val = val % 300;
+            match ops[idx] % 5 {
+                0 => {
+                    let pos = expected.iter().position(|it| *it == val);
+                    assert_eq!(tree.remove(&val), pos.map(|pos| expected.remove(pos)));
+                }
+                1 => {
+                    let min = (!expected.is_empty()).then(|| expected.remove(0));
+                    assert_eq!(tree.pop_min(), min);
+                }
+                2 => assert_eq!(tree.pop_max(), expected.pop()),
+                _ => {
+                    tree.insert(val);
+                    expected.push(val);
+                    expected.sort();
+                }
+            }
+            assert_eq!(check(&tree.root, None, None), expected.len());
+        }
+        assert!(tree.iter().eq(expected.iter()));
+    }
+}
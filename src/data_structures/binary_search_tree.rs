@@ -1,135 +1,400 @@
-use std::cmp::Ordering;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::{self, Vec};
+use core::cmp::Ordering;
+use core::iter::{Chain, FusedIterator};
+use core::ops::{Bound, RangeBounds};
+use core::{option, slice};
 
-struct Node<T>
+use super::bst_node::{
+    self, build, count_below, delete, drop_link, into_nodes, size, take, IntoNodes, Item, Nodes,
+};
+use crate::sort::td_merge_sort;
+
+/// 插入与已有元素相等的值时的处理方式，在创建二叉搜索树时指定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    /// 作为新节点插入，相等的元素分散在不同的节点中
+    #[default]
+    Allow,
+    /// 不插入新值，即集合语义
+    Reject,
+    /// 用新值替换已有的元素
+    Replace,
+    /// 将新值存入已有元素所在的节点，每个节点记录相等元素的重数，即多重集合语义
+    Count,
+}
+
+/// 比较两个元素的大小，用于自定义二叉搜索树中元素的顺序
+///
+/// 返回值的含义与 [`PartialOrd::partial_cmp`] 相同，`Fn(&T, &T) -> Option<Ordering>` 的闭包和函数都实现了该 trait，
+/// 也可以使用 `Box<dyn Compare<T>>` 在运行时选择比较方式。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::data_structures::{Compare, NaturalOrder};
+/// use std::cmp::Ordering;
+///
+/// let reverse = |a: &i32, b: &i32| b.partial_cmp(a);
+/// assert_eq!(reverse.compare(&1, &2), Some(Ordering::Greater));
+/// assert_eq!(NaturalOrder.compare(&1, &2), Some(Ordering::Less));
+/// ```
+pub trait Compare<T> {
+    /// 比较 a 与 b 的大小，两者无法比较时返回 None
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering>;
+}
+
+impl<T, F> Compare<T> for F
 where
-    T: PartialOrd,
+    F: Fn(&T, &T) -> Option<Ordering>,
 {
-    val: T,
-    left: Link<T>,
-    right: Link<T>,
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        self(a, b)
+    }
 }
 
-impl<T> Node<T>
+impl<T> Compare<T> for Box<dyn Compare<T> + '_> {
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        (**self).compare(a, b)
+    }
+}
+
+/// 按元素的 [`PartialOrd`] 实现比较，是二叉搜索树默认的比较方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NaturalOrder;
+
+impl<T> Compare<T> for NaturalOrder
 where
     T: PartialOrd,
 {
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        a.partial_cmp(b)
+    }
+}
+
+/// 节点中一组相等的元素
+struct Elements<T> {
+    val: T,
+    /// 与 val 相等的其余元素，只在 [`DuplicatePolicy::Count`] 下使用
+    duplicates: Vec<T>,
+}
+
+impl<T> Elements<T> {
     fn new(val: T) -> Self {
         Self {
             val,
-            left: None,
-            right: None,
+            duplicates: Vec::new(),
         }
     }
+}
 
-    fn insert(&mut self, val: T) {
-        let target_node = if self.val < val {
-            &mut self.left
-        } else {
-            &mut self.right
-        };
+impl<T> Item for Elements<T> {
+    fn multiplicity(&self) -> usize {
+        self.duplicates.len() + 1
+    }
+}
 
-        match target_node {
-            None => *target_node = Some(Box::new(Node::new(val))),
-            Some(ref mut node) => {
-                node.insert(val);
+/// 节点中的所有元素
+type Values<'a, T> = Chain<option::IntoIter<&'a T>, slice::Iter<'a, T>>;
+
+/// 取出节点中的所有元素
+type IntoValues<T> = Chain<option::IntoIter<T>, vec::IntoIter<T>>;
+
+type Node<T> = bst_node::Node<Elements<T>>;
+
+type Link<T> = bst_node::Link<Elements<T>>;
+
+fn no_values<'a, T>() -> Values<'a, T> {
+    None.into_iter().chain(&[])
+}
+
+fn no_into_values<T>() -> IntoValues<T> {
+    None.into_iter().chain(Vec::new())
+}
+
+impl<T> Node<T> {
+    fn values(&self) -> Values<'_, T> {
+        Some(&self.item.val)
+            .into_iter()
+            .chain(&self.item.duplicates)
+    }
+
+    fn into_values(self) -> IntoValues<T> {
+        Some(self.item.val).into_iter().chain(self.item.duplicates)
+    }
+
+    fn insert<C>(&mut self, val: T, comparator: &C)
+    where
+        C: Compare<T>,
+    {
+        let mut node = self;
+        loop {
+            node.size += 1;
+            let target_node = if comparator.compare(&node.item.val, &val) == Some(Ordering::Less) {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+
+            match target_node {
+                None => {
+                    *target_node = Some(Box::new(Node::new(Elements::new(val))));
+                    return;
+                }
+                Some(next) => node = next,
             }
         }
     }
 
-    fn search(&self, val: &T) -> bool {
-        if self.val == *val {
-            return true;
-        }
+    fn search<C>(&self, val: &T, comparator: &C) -> bool
+    where
+        C: Compare<T>,
+    {
+        let mut node = self;
+        loop {
+            let ordering = comparator.compare(&node.item.val, val);
+            if ordering == Some(Ordering::Equal) {
+                return true;
+            }
 
-        let target_node = if self.val < *val {
-            &self.left
-        } else {
-            &self.right
-        };
+            let target_node = if ordering == Some(Ordering::Less) {
+                &node.left
+            } else {
+                &node.right
+            };
 
-        match target_node {
-            None => false,
-            Some(ref node) => node.search(val),
+            match target_node {
+                None => return false,
+                Some(next) => node = next,
+            }
         }
     }
 
     fn max(&self) -> Option<&T> {
-        match &self.left {
-            None => Some(&self.val),
-            Some(left_node) => left_node.max(),
+        let mut node = self;
+        while let Some(left_node) = &node.left {
+            node = left_node;
         }
+        Some(&node.item.val)
     }
 
     fn min(&self) -> Option<&T> {
-        match &self.right {
-            None => Some(&self.val),
-            Some(right_node) => right_node.min(),
+        let mut node = self;
+        while let Some(right_node) = &node.right {
+            node = right_node;
         }
+        Some(&node.item.val)
     }
 
-    fn floor(&self, val: &T) -> Option<&T> {
-        match self.val.partial_cmp(val) {
-            Some(Ordering::Greater) => {
-                // node.val > val
-                match &self.right {
-                    None => None,
-                    Some(right_node) => right_node.floor(val),
-                }
-            }
-            Some(Ordering::Less) => {
-                // node.val < val
-                match &self.left {
-                    None => Some(&self.val),
-                    Some(left_node) => {
-                        let floor_node = left_node.floor(val);
-                        match floor_node {
-                            None => Some(&self.val),
-                            Some(_) => floor_node,
-                        }
-                    }
+    fn floor<C>(&self, val: &T, comparator: &C) -> Option<&T>
+    where
+        C: Compare<T>,
+    {
+        // 查找路径上最后一个小于 val 的节点
+        let mut floor_node = None;
+        let mut node = self;
+        loop {
+            let target_node = match comparator.compare(&node.item.val, val) {
+                // node.item.val > val
+                Some(Ordering::Greater) => &node.right,
+                // node.item.val < val
+                Some(Ordering::Less) => {
+                    floor_node = Some(&node.item.val);
+                    &node.left
                 }
+                Some(Ordering::Equal) => return Some(&node.item.val),
+                None => return floor_node,
+            };
+
+            match target_node {
+                None => return floor_node,
+                Some(next) => node = next,
             }
-            Some(Ordering::Equal) => Some(&self.val),
-            None => None,
         }
     }
 
-    fn ceil(&self, val: &T) -> Option<&T> {
-        match self.val.partial_cmp(val) {
-            Some(Ordering::Greater) => {
-                // node.val > val
-                match &self.right {
-                    None => Some(&self.val),
-                    Some(right_node) => {
-                        let ceil_node = right_node.ceil(val);
-                        match ceil_node {
-                            Some(_) => ceil_node,
-                            None => Some(&self.val),
-                        }
-                    }
-                }
-            }
-            Some(Ordering::Less) => {
-                // node.val < val
-                match &self.left {
-                    None => None,
-                    Some(left_node) => left_node.ceil(val),
+    fn ceil<C>(&self, val: &T, comparator: &C) -> Option<&T>
+    where
+        C: Compare<T>,
+    {
+        // 查找路径上最后一个大于 val 的节点
+        let mut ceil_node = None;
+        let mut node = self;
+        loop {
+            let target_node = match comparator.compare(&node.item.val, val) {
+                // node.item.val > val
+                Some(Ordering::Greater) => {
+                    ceil_node = Some(&node.item.val);
+                    &node.right
                 }
+                // node.item.val < val
+                Some(Ordering::Less) => &node.left,
+                Some(Ordering::Equal) => return Some(&node.item.val),
+                None => return ceil_node,
+            };
+
+            match target_node {
+                None => return ceil_node,
+                Some(next) => node = next,
             }
-            Some(Ordering::Equal) => Some(&self.val),
-            None => None,
         }
     }
 }
 
-type Link<T> = Option<Box<Node<T>>>;
+/// 删除子树中等于指定值的一个元素，返回被删除的值
+///
+/// 先确认存在相等的元素，再沿查找路径减少经过节点的元素数量。
+fn remove<T, C>(link: &mut Link<T>, val: &T, comparator: &C) -> Option<T>
+where
+    C: Compare<T>,
+{
+    let mut target = link.as_deref();
+    loop {
+        let node = target?;
+        target = match comparator.compare(&node.item.val, val)? {
+            // node.val < val
+            Ordering::Less => node.left.as_deref(),
+            // node.val > val
+            Ordering::Greater => node.right.as_deref(),
+            Ordering::Equal => break,
+        };
+    }
+
+    let mut link = link;
+    loop {
+        let ordering = comparator.compare(&link.as_ref()?.item.val, val)?;
+        if ordering == Ordering::Equal {
+            return pop_root(link);
+        }
 
-/// 二叉搜索树
-pub struct BinarySearchTree<T>
+        let node = link.as_mut()?;
+        node.size -= 1;
+        link = if ordering == Ordering::Less {
+            // node.val < val
+            &mut node.left
+        } else {
+            // node.val > val
+            &mut node.right
+        };
+    }
+}
+
+/// 删除子树中所有等于指定值的元素，count 为这些元素的数量
+///
+/// 相等的元素都在查找路径上第一个相等节点的子树中：沿查找路径只减去一次 count，
+/// 再从该子树中摘下相等的节点（连同其中相等的元素），Count 策略下只有一个这样的节点。
+fn remove_all<T, C>(link: &mut Link<T>, val: &T, comparator: &C, count: usize)
 where
-    T: PartialOrd,
+    C: Compare<T>,
+{
+    let mut link = link;
+    loop {
+        let Some(ordering) = link
+            .as_ref()
+            .and_then(|node| comparator.compare(&node.item.val, val))
+        else {
+            return;
+        };
+        if ordering == Ordering::Equal {
+            break;
+        }
+
+        let Some(node) = link.as_mut() else {
+            return;
+        };
+        node.size -= count;
+        link = if ordering == Ordering::Less {
+            // node.val < val
+            &mut node.left
+        } else {
+            // node.val > val
+            &mut node.right
+        };
+    }
+    while take(link, |it| comparator.compare(&it.val, val)).is_some() {}
+}
+
+/// 删除子树中的一个最小元素，返回其值
+fn pop_min<T>(link: &mut Link<T>) -> Option<T> {
+    let mut link = link;
+    while link.as_ref()?.right.is_some() {
+        let node = link.as_mut()?;
+        node.size -= 1;
+        link = &mut node.right;
+    }
+    pop_root(link)
+}
+
+/// 删除子树中的一个最大元素，返回其值
+fn pop_max<T>(link: &mut Link<T>) -> Option<T> {
+    let mut link = link;
+    while link.as_ref()?.left.is_some() {
+        let node = link.as_mut()?;
+        node.size -= 1;
+        link = &mut node.left;
+    }
+    pop_root(link)
+}
+
+/// 删除子树根节点中的一个元素，返回其值
+fn pop_root<T>(link: &mut Link<T>) -> Option<T> {
+    let node = link.as_mut()?;
+    if let Some(duplicate) = node.item.duplicates.pop() {
+        node.size -= 1;
+        return Some(duplicate);
+    }
+    delete(link).map(|node| node.item.val)
+}
+
+/// 按从小到大的顺序删除子树中不满足条件的元素，剩余的节点重新组成一棵平衡的子树
+fn retain<T, F>(link: &mut Link<T>, f: &mut F)
+where
+    F: FnMut(&T) -> bool,
 {
+    let mut nodes = into_nodes(link.take());
+    nodes.retain_mut(|node| {
+        let keep_val = f(&node.item.val);
+        node.item.duplicates.retain(|it| f(it));
+        if keep_val {
+            return true;
+        }
+        if node.item.duplicates.is_empty() {
+            return false;
+        }
+        node.item.val = node.item.duplicates.remove(0);
+        true
+    });
+    let len = nodes.len();
+    *link = build(&mut nodes.into_iter(), len);
+}
+
+/// 用 [`td_merge_sort`] 将元素按从小到大的顺序排序，相等的元素保持原有的顺序
+///
+/// td_merge_sort 要求元素实现 Copy，这里对元素的引用连同下标排序，再按排好的下标依次取出元素。
+fn merge_sorted<T>(vec: Vec<T>) -> Vec<T>
+where
+    T: Ord,
+{
+    let mut order: Vec<(&T, usize)> = vec.iter().zip(0..).collect();
+    td_merge_sort(&mut order);
+    let order: Vec<usize> = order.into_iter().map(|(_, index)| index).collect();
+
+    let mut vec: Vec<Option<T>> = vec.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|index| vec[index].take())
+        .collect()
+}
+
+/// 二叉搜索树
+///
+/// 插入相等元素时的行为由创建时指定的 [`DuplicatePolicy`] 决定，默认为 [`DuplicatePolicy::Allow`]。
+/// 元素的顺序由比较器 `C` 决定，默认按元素的 [`PartialOrd`] 实现比较，见 [`Compare`]。
+pub struct BinarySearchTree<T, C = NaturalOrder> {
     root: Link<T>,
+    policy: DuplicatePolicy,
+    comparator: C,
 }
 
 impl<T> BinarySearchTree<T>
@@ -145,10 +410,111 @@ where
     /// let tree:BinarySearchTree<i32> = BinarySearchTree::new();
     /// ```
     pub fn new() -> Self {
-        Self { root: None }
+        Self::with_policy(DuplicatePolicy::default())
+    }
+
+    /// 创建一个使用指定重复元素处理方式的空二叉搜索树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{BinarySearchTree, DuplicatePolicy};
+    /// let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Reject);
+    ///
+    /// assert!(tree.insert(1));
+    /// assert!(!tree.insert(1));
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn with_policy(policy: DuplicatePolicy) -> Self {
+        Self::with_comparator_and_policy(NaturalOrder, policy)
+    }
+
+    /// 用按从小到大排序的元素构建一棵完全平衡的二叉搜索树，时间复杂度为 O(n)
+    ///
+    /// 元素未排序时构建出的树不满足二叉搜索树的性质。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let tree = BinarySearchTree::from_sorted(vec![1, 2, 3, 4, 5, 6, 7]);
+    ///
+    /// assert!(tree.pre_order().eq(&[4, 2, 1, 3, 6, 5, 7]));
+    /// ```
+    pub fn from_sorted(vec: Vec<T>) -> Self {
+        debug_assert!(vec.windows(2).all(|it| it[0] <= it[1]));
+        let len = vec.len();
+        let mut tree = Self::new();
+        let mut nodes = vec
+            .into_iter()
+            .map(|val| Box::new(Node::new(Elements::new(val))));
+        tree.root = build(&mut nodes, len);
+        tree
+    }
+}
+
+impl<T, C> BinarySearchTree<T, C>
+where
+    C: Compare<T>,
+{
+    /// 创建一个使用指定比较器的空二叉搜索树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::with_comparator(|a: &i32, b: &i32| b.partial_cmp(a));
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.iter().eq(&[3, 2, 1]));
+    /// ```
+    pub fn with_comparator(comparator: C) -> Self {
+        Self::with_comparator_and_policy(comparator, DuplicatePolicy::default())
+    }
+
+    /// 创建一个使用指定比较器和重复元素处理方式的空二叉搜索树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{BinarySearchTree, DuplicatePolicy};
+    /// let mut tree = BinarySearchTree::with_comparator_and_policy(
+    ///     |a: &&str, b: &&str| a.to_lowercase().partial_cmp(&b.to_lowercase()),
+    ///     DuplicatePolicy::Reject,
+    /// );
+    ///
+    /// assert!(tree.insert("Rust"));
+    /// assert!(!tree.insert("rust"));
+    /// assert!(tree.search(&"RUST"));
+    /// ```
+    pub fn with_comparator_and_policy(comparator: C, policy: DuplicatePolicy) -> Self {
+        Self {
+            root: None,
+            policy,
+            comparator,
+        }
+    }
+
+    /// 获取插入相等元素时的处理方式
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{BinarySearchTree, DuplicatePolicy};
+    /// let tree: BinarySearchTree<i32> = BinarySearchTree::new();
+    ///
+    /// assert_eq!(tree.policy(), DuplicatePolicy::Allow);
+    /// ```
+    pub fn policy(&self) -> DuplicatePolicy {
+        self.policy
     }
 
-    /// 向二叉搜索树添加一个元素
+    /// 向二叉搜索树添加一个元素，返回是否新建了节点
+    ///
+    /// [`DuplicatePolicy::Allow`] 下总是新建节点；其他处理方式下已存在相等的元素时返回 false，
+    /// 并按处理方式丢弃新值、替换已有的元素或将新值存入已有的节点。
     ///
     /// # Example
     ///
@@ -156,14 +522,78 @@ where
     /// use rust_demo::data_structures::BinarySearchTree;
     /// let mut tree = BinarySearchTree::new();
     ///
-    /// tree.insert(1);
+    /// assert!(tree.insert(1));
+    /// assert!(tree.insert(1));
+    /// assert_eq!(tree.len(), 2);
     /// ```
-    pub fn insert(&mut self, val: T) {
+    pub fn insert(&mut self, val: T) -> bool {
+        if self.policy != DuplicatePolicy::Allow && self.search(&val) {
+            match self.policy {
+                DuplicatePolicy::Replace => {
+                    if let Some(node) = self.find_mut(&val) {
+                        node.item.val = val;
+                    }
+                }
+                DuplicatePolicy::Count => self.push_duplicate(val),
+                DuplicatePolicy::Reject | DuplicatePolicy::Allow => {}
+            }
+            return false;
+        }
+
         match &mut self.root {
-            None => self.root = Some(Box::new(Node::new(val))),
+            None => self.root = Some(Box::new(Node::new(Elements::new(val)))),
             Some(ref mut node) => {
-                node.insert(val);
+                node.insert(val, &self.comparator);
+            }
+        }
+        true
+    }
+
+    /// 判断 a 是否小于 b
+    fn is_less(&self, a: &T, b: &T) -> bool {
+        self.comparator.compare(a, b) == Some(Ordering::Less)
+    }
+
+    /// 判断 a 是否小于或等于 b
+    fn is_at_most(&self, a: &T, b: &T) -> bool {
+        matches!(
+            self.comparator.compare(a, b),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// 查找等于指定值的节点，查找路径与 `search` 相同
+    fn find_mut(&mut self, val: &T) -> Option<&mut Node<T>> {
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            let ordering = self.comparator.compare(&node.item.val, val);
+            if ordering == Some(Ordering::Equal) {
+                return Some(node);
+            }
+            link = if ordering == Some(Ordering::Less) {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+        None
+    }
+
+    /// 将值存入与其相等的节点中，要求树中存在相等的元素
+    fn push_duplicate(&mut self, val: T) {
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            node.size += 1;
+            let ordering = self.comparator.compare(&node.item.val, &val);
+            if ordering == Some(Ordering::Equal) {
+                node.item.duplicates.push(val);
+                return;
             }
+            link = if ordering == Some(Ordering::Less) {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
         }
     }
 
@@ -180,7 +610,7 @@ where
     pub fn search(&self, val: &T) -> bool {
         match self.root {
             None => false,
-            Some(ref node) => node.search(val),
+            Some(ref node) => node.search(val, &self.comparator),
         }
     }
 
@@ -231,7 +661,7 @@ where
     pub fn floor(&self, val: &T) -> Option<&T> {
         match &self.root {
             None => None,
-            Some(node) => node.floor(val),
+            Some(node) => node.floor(val, &self.comparator),
         }
     }
 
@@ -248,47 +678,1536 @@ where
     pub fn ceil(&self, val: &T) -> Option<&T> {
         match &self.root {
             None => None,
-            Some(node) => node.ceil(val),
+            Some(node) => node.ceil(val, &self.comparator),
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::BinarySearchTree;
-
-    fn perpare_tree() -> BinarySearchTree<i32> {
-        let mut result = BinarySearchTree::new();
-        result.insert(1);
-        result.insert(2);
-        result.insert(3);
-        result.insert(5);
-        result.insert(6);
-        result.insert(7);
-        result
+    /// 删除二叉搜索树中等于指定值的一个元素，返回被删除的元素，与 `remove_one` 相同
+    ///
+    /// 被删除的节点有两个子节点时，使用其后继节点替换（Hibbard 删除）。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.remove(&1), Some(1));
+    /// assert_eq!(tree.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, val: &T) -> Option<T> {
+        self.remove_one(val)
     }
 
-    #[test]
-    fn test_search() {
-        let tree = perpare_tree();
-        assert!(tree.search(&1));
-        assert!(tree.search(&2));
-        assert!(tree.search(&3));
+    /// 删除二叉搜索树中等于指定值的一个元素，返回被删除的元素
+    ///
+    /// 节点中还有其他相等的元素时只删除其中一个，否则删除整个节点。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{BinarySearchTree, DuplicatePolicy};
+    /// let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Count);
+    /// tree.insert(1);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.remove_one(&1), Some(1));
+    /// assert_eq!(tree.count(&1), 1);
+    /// ```
+    pub fn remove_one(&mut self, val: &T) -> Option<T> {
+        remove(&mut self.root, val, &self.comparator)
+    }
+
+    /// 删除二叉搜索树中所有等于指定值的元素，返回被删除的元素数量
+    ///
+    /// 根节点到相等元素所在子树的路径只更新一次；[`DuplicatePolicy::Count`] 下相等的元素在同一个节点中，
+    /// 时间复杂度为 O(h)。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [1, 2, 1, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert_eq!(tree.remove_all(&1), 3);
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn remove_all(&mut self, val: &T) -> usize {
+        let count = self.count(val);
+        if count > 0 {
+            remove_all(&mut self.root, val, &self.comparator, count);
+        }
+        count
+    }
 
-        assert!(!tree.search(&4));
+    /// 获取二叉搜索树中等于指定值的元素数量，时间复杂度为 O(h)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{BinarySearchTree, DuplicatePolicy};
+    /// let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Count);
+    /// for val in [1, 2, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert_eq!(tree.count(&1), 2);
+    /// assert_eq!(tree.count(&3), 0);
+    /// ```
+    pub fn count(&self, val: &T) -> usize {
+        let below = count_below(&self.root, |it| self.is_less(&it.val, val));
+        let up_to = count_below(&self.root, |it| self.is_at_most(&it.val, val));
+        up_to - below
     }
 
-    #[test]
-    fn test_max_and_min() {
-        let tree = perpare_tree();
-        assert_eq!(tree.max(), Some(&7));
-        assert_eq!(tree.min(), Some(&1));
+    /// 删除并返回二叉搜索树中的最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_min(), Some(1));
+    /// assert_eq!(tree.min(), Some(&2));
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        pop_min(&mut self.root)
     }
 
-    #[test]
-    fn test_floor_and_ceil() {
-        let tree = perpare_tree();
-        assert_eq!(tree.floor(&4), Some(&3));
-        assert_eq!(tree.ceil(&4), Some(&5));
+    /// 删除并返回二叉搜索树中的最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_max(), Some(2));
+    /// assert_eq!(tree.max(), Some(&1));
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        pop_max(&mut self.root)
+    }
+
+    /// 只保留满足条件的元素，按从小到大的顺序对每个元素调用一次判断函数
+    ///
+    /// 剩余的节点会重新组成一棵平衡的树，时间复杂度为 O(n)。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in 1..=6 {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// tree.retain(|val| val % 2 == 0);
+    /// assert_eq!(tree.min(), Some(&2));
+    /// assert_eq!(tree.search(&3), false);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        retain(&mut self.root, &mut f);
+    }
+
+    /// 将二叉搜索树调整为完全平衡的形状，时间复杂度为 O(n)，只使用 O(1) 的额外空间
+    ///
+    /// 使用 Day–Stout–Warren 算法：先通过旋转把树拉直成一条链，再沿链反复旋转压缩成平衡的树。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in 1..=7 {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// tree.rebalance();
+    /// assert!(tree.pre_order().eq(&[4, 2, 1, 3, 6, 5, 7]));
+    /// ```
+    pub fn rebalance(&mut self) {
+        bst_node::rebalance(&mut self.root);
+    }
+
+    /// 获取二叉搜索树中的元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// tree.insert(1);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// 判断二叉搜索树是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let tree: BinarySearchTree<i32> = BinarySearchTree::new();
+    ///
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 获取二叉搜索树中小于指定值的元素数量，时间复杂度为 O(h)，h 为树的高度
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [1, 3, 5] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert_eq!(tree.rank(&3), 1);
+    /// assert_eq!(tree.rank(&4), 2);
+    /// ```
+    pub fn rank(&self, val: &T) -> usize {
+        count_below(&self.root, |it| self.is_less(&it.val, val))
+    }
+
+    /// 获取二叉搜索树中第 k 小的元素（k 从 0 开始），时间复杂度为 O(h)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [3, 1, 5] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert_eq!(tree.select(1), Some(&3));
+    /// assert_eq!(tree.select(3), None);
+    /// ```
+    pub fn select(&self, k: usize) -> Option<&T> {
+        let mut k = k;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let smaller = size(&node.right);
+            if k < smaller {
+                link = &node.right;
+            } else if k < smaller + node.multiplicity() {
+                return node.values().nth(k - smaller);
+            } else {
+                k -= smaller + node.multiplicity();
+                link = &node.left;
+            }
+        }
+        None
+    }
+
+    /// 获取二叉搜索树中位于 [lo, hi] 区间内的元素数量，时间复杂度为 O(h)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [1, 3, 5, 7] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert_eq!(tree.count_range(&2, &5), 2);
+    /// assert_eq!(tree.count_range(&5, &2), 0);
+    /// ```
+    pub fn count_range(&self, lo: &T, hi: &T) -> usize {
+        let below_lo = count_below(&self.root, |it| self.is_less(&it.val, lo));
+        let up_to_hi = count_below(&self.root, |it| self.is_at_most(&it.val, hi));
+        up_to_hi.saturating_sub(below_lo)
+    }
+
+    /// 获取按从小到大的顺序（中序遍历）迭代元素的迭代器，使用 `rev()` 可按从大到小的顺序迭代
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.iter().eq(&[1, 2, 3]));
+    /// assert!(tree.iter().rev().eq(&[3, 2, 1]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            nodes: Nodes::new(&self.root),
+            front_values: no_values(),
+            back_values: no_values(),
+            len: self.len(),
+        }
+    }
+
+    /// 获取按从小到大的顺序迭代指定范围内元素的迭代器
+    ///
+    /// 只访问范围内的节点以及查找范围边界时经过的节点，范围外的子树不会被访问。
+    /// 范围的起点大于终点时返回空的迭代器。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [5, 1, 4, 2, 3] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.range(2..4).eq(&[2, 3]));
+    /// assert!(tree.range(3..).rev().eq(&[5, 4, 3]));
+    /// ```
+    pub fn range<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<T>,
+    {
+        let is_below = |it: &Elements<T>| match range.start_bound() {
+            Bound::Included(lo) => self.is_less(&it.val, lo),
+            Bound::Excluded(lo) => self.is_at_most(&it.val, lo),
+            Bound::Unbounded => false,
+        };
+        let is_above = |it: &Elements<T>| match range.end_bound() {
+            Bound::Included(hi) => self.is_less(hi, &it.val),
+            Bound::Excluded(hi) => self.is_at_most(hi, &it.val),
+            Bound::Unbounded => false,
+        };
+
+        let up_to_end = count_below(&self.root, |it| !is_above(it));
+        Iter {
+            len: up_to_end.saturating_sub(count_below(&self.root, is_below)),
+            nodes: Nodes::range(&self.root, is_below, is_above),
+            front_values: no_values(),
+            back_values: no_values(),
+        }
+    }
+
+    /// 获取前序遍历的迭代器，先访问节点，再依次访问较小和较大的子树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.pre_order().eq(&[2, 1, 3]));
+    /// ```
+    pub fn pre_order(&self) -> PreOrder<'_, T> {
+        PreOrder {
+            stack: self.root.as_deref().into_iter().collect(),
+            values: no_values(),
+            len: self.len(),
+        }
+    }
+
+    /// 获取后序遍历的迭代器，先依次访问较小和较大的子树，再访问节点
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.post_order().eq(&[1, 3, 2]));
+    /// ```
+    pub fn post_order(&self) -> PostOrder<'_, T> {
+        PostOrder {
+            stack: self
+                .root
+                .as_deref()
+                .map(|it| (it, false))
+                .into_iter()
+                .collect(),
+            values: no_values(),
+            len: self.len(),
+        }
+    }
+
+    /// 获取层序遍历的迭代器，逐层访问节点，同一层中的节点按从小到大的顺序访问
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1, 4] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.level_order().eq(&[2, 1, 3, 4]));
+    /// ```
+    pub fn level_order(&self) -> LevelOrder<'_, T> {
+        LevelOrder {
+            queue: self.root.as_deref().into_iter().collect(),
+            values: no_values(),
+            len: self.len(),
+        }
+    }
+
+    /// 获取按前序遍历的顺序取出所有元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.into_pre_order().eq([2, 1, 3]));
+    /// ```
+    pub fn into_pre_order(mut self) -> IntoPreOrder<T> {
+        IntoPreOrder {
+            len: self.len(),
+            stack: self.root.take().into_iter().collect(),
+            values: no_into_values(),
+        }
+    }
+
+    /// 获取按后序遍历的顺序取出所有元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.into_post_order().eq([1, 3, 2]));
+    /// ```
+    pub fn into_post_order(mut self) -> IntoPostOrder<T> {
+        IntoPostOrder {
+            len: self.len(),
+            stack: self.root.take().into_iter().collect(),
+            values: no_into_values(),
+        }
+    }
+
+    /// 获取按层序遍历的顺序取出所有元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1, 4] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.into_level_order().eq([2, 1, 3, 4]));
+    /// ```
+    pub fn into_level_order(mut self) -> IntoLevelOrder<T> {
+        IntoLevelOrder {
+            len: self.len(),
+            queue: self.root.take().into_iter().collect(),
+            values: no_into_values(),
+        }
+    }
+}
+
+impl<T, C> Default for BinarySearchTree<T, C>
+where
+    C: Compare<T> + Default,
+{
+    fn default() -> Self {
+        Self::with_comparator(C::default())
+    }
+}
+
+impl<T> FromIterator<T> for BinarySearchTree<T>
+where
+    T: Ord,
+{
+    /// 先用 [`td_merge_sort`] 排序，再构建完全平衡的二叉搜索树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let tree: BinarySearchTree<_> = [3, 1, 2].into_iter().collect();
+    ///
+    /// assert!(tree.pre_order().eq(&[2, 1, 3]));
+    /// ```
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_sorted(merge_sorted(iter.into_iter().collect()))
+    }
+}
+
+impl<T> Extend<T> for BinarySearchTree<T>
+where
+    T: Ord,
+{
+    /// 先用 [`td_merge_sort`] 排序，再按中间元素优先的顺序逐个插入，有序的输入不会使树退化
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// tree.extend(1..=3);
+    ///
+    /// assert!(tree.pre_order().eq(&[2, 1, 3]));
+    /// ```
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let vec = merge_sorted(iter.into_iter().collect());
+        let mut vec: Vec<Option<T>> = vec.into_iter().map(Some).collect();
+
+        let mut ranges = Vec::from([(0, vec.len())]);
+        while let Some((lo, hi)) = ranges.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let mid = lo + (hi - lo) / 2;
+            if let Some(val) = vec[mid].take() {
+                self.insert(val);
+            }
+            ranges.push((mid + 1, hi));
+            ranges.push((lo, mid));
+        }
+    }
+}
+
+impl<T, C> Drop for BinarySearchTree<T, C> {
+    fn drop(&mut self) {
+        drop_link(self.root.take());
+    }
+}
+
+impl<'a, T, C> IntoIterator for &'a BinarySearchTree<T, C>
+where
+    C: Compare<T>,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T, C> IntoIterator for BinarySearchTree<T, C>
+where
+    C: Compare<T>,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// 获取按从小到大的顺序取出所有元素的迭代器，使用 `rev()` 可按从大到小的顺序取出
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BinarySearchTree;
+    /// let mut tree = BinarySearchTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert_eq!(tree.into_iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    fn into_iter(mut self) -> IntoIter<T> {
+        IntoIter {
+            len: self.len(),
+            nodes: IntoNodes::new(self.root.take()),
+            front_values: no_into_values(),
+            back_values: no_into_values(),
+        }
+    }
+}
+
+/// 中序遍历的迭代器
+///
+/// 节点由 `Nodes` 从两端依次给出，通过剩余元素数量判断两端是否相遇。
+/// 节点中相等的元素依次从 front_values 和 back_values 的两端返回。
+pub struct Iter<'a, T> {
+    nodes: Nodes<'a, Elements<T>>,
+    front_values: Values<'a, T>,
+    back_values: Values<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        loop {
+            if let Some(val) = self.front_values.next() {
+                self.len -= 1;
+                return Some(val);
+            }
+            self.front_values = self.nodes.next_front()?.values();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        loop {
+            if let Some(val) = self.back_values.next_back() {
+                self.len -= 1;
+                return Some(val);
+            }
+            self.back_values = self.nodes.next_back()?.values();
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// 按中序遍历取出所有元素的迭代器
+///
+/// 节点由 `IntoNodes` 从两端依次取出，两端都没有剩余的节点时，剩余的元素都在另一端正在返回的节点中。
+pub struct IntoIter<T> {
+    nodes: IntoNodes<Elements<T>>,
+    front_values: IntoValues<T>,
+    back_values: IntoValues<T>,
+    len: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        loop {
+            if let Some(val) = self.front_values.next() {
+                return Some(val);
+            }
+            match self.nodes.next_front() {
+                Some(node) => self.front_values = node.into_values(),
+                None => return self.back_values.next(),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        loop {
+            if let Some(val) = self.back_values.next_back() {
+                return Some(val);
+            }
+            match self.nodes.next_back() {
+                Some(node) => self.back_values = node.into_values(),
+                None => return self.front_values.next_back(),
+            }
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// 前序遍历的迭代器
+pub struct PreOrder<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    values: Values<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for PreOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(val) = self.values.next() {
+                self.len -= 1;
+                return Some(val);
+            }
+            let node = self.stack.pop()?;
+            // 较小的子树后入栈，先被访问
+            self.stack.extend(node.left.as_deref());
+            self.stack.extend(node.right.as_deref());
+            self.values = node.values();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for PreOrder<'_, T> {}
+
+impl<T> FusedIterator for PreOrder<'_, T> {}
+
+/// 后序遍历的迭代器
+pub struct PostOrder<'a, T> {
+    /// 节点以及其子树是否已经入栈
+    stack: Vec<(&'a Node<T>, bool)>,
+    values: Values<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for PostOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(val) = self.values.next() {
+                self.len -= 1;
+                return Some(val);
+            }
+            let (node, expanded) = self.stack.pop()?;
+            if expanded {
+                self.values = node.values();
+                continue;
+            }
+
+            self.stack.push((node, true));
+            self.stack
+                .extend(node.left.as_deref().map(|it| (it, false)));
+            self.stack
+                .extend(node.right.as_deref().map(|it| (it, false)));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for PostOrder<'_, T> {}
+
+impl<T> FusedIterator for PostOrder<'_, T> {}
+
+/// 层序遍历的迭代器
+pub struct LevelOrder<'a, T> {
+    queue: VecDeque<&'a Node<T>>,
+    values: Values<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for LevelOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(val) = self.values.next() {
+                self.len -= 1;
+                return Some(val);
+            }
+            let node = self.queue.pop_front()?;
+            self.queue.extend(node.right.as_deref());
+            self.queue.extend(node.left.as_deref());
+            self.values = node.values();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for LevelOrder<'_, T> {}
+
+impl<T> FusedIterator for LevelOrder<'_, T> {}
+
+/// 按前序遍历取出所有元素的迭代器
+pub struct IntoPreOrder<T> {
+    stack: Vec<Box<Node<T>>>,
+    values: IntoValues<T>,
+    len: usize,
+}
+
+impl<T> Drop for IntoPreOrder<T> {
+    fn drop(&mut self) {
+        for node in self.stack.drain(..) {
+            drop_link(Some(node));
+        }
+    }
+}
+
+impl<T> Iterator for IntoPreOrder<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(val) = self.values.next() {
+                self.len -= 1;
+                return Some(val);
+            }
+            let mut node = self.stack.pop()?;
+            self.stack.extend(node.left.take());
+            self.stack.extend(node.right.take());
+            self.values = node.into_values();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoPreOrder<T> {}
+
+impl<T> FusedIterator for IntoPreOrder<T> {}
+
+/// 按后序遍历取出所有元素的迭代器
+pub struct IntoPostOrder<T> {
+    stack: Vec<Box<Node<T>>>,
+    values: IntoValues<T>,
+    len: usize,
+}
+
+impl<T> Drop for IntoPostOrder<T> {
+    fn drop(&mut self) {
+        for node in self.stack.drain(..) {
+            drop_link(Some(node));
+        }
+    }
+}
+
+impl<T> Iterator for IntoPostOrder<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(val) = self.values.next() {
+                self.len -= 1;
+                return Some(val);
+            }
+            let mut node = self.stack.pop()?;
+            if node.left.is_none() && node.right.is_none() {
+                self.values = node.into_values();
+                continue;
+            }
+
+            // 取出子树后节点重新入栈，子树全部返回后再返回节点
+            let left = node.left.take();
+            let right = node.right.take();
+            self.stack.push(node);
+            self.stack.extend(left);
+            self.stack.extend(right);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoPostOrder<T> {}
+
+impl<T> FusedIterator for IntoPostOrder<T> {}
+
+/// 按层序遍历取出所有元素的迭代器
+pub struct IntoLevelOrder<T> {
+    queue: VecDeque<Box<Node<T>>>,
+    values: IntoValues<T>,
+    len: usize,
+}
+
+impl<T> Drop for IntoLevelOrder<T> {
+    fn drop(&mut self) {
+        for node in self.queue.drain(..) {
+            drop_link(Some(node));
+        }
+    }
+}
+
+impl<T> Iterator for IntoLevelOrder<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(val) = self.values.next() {
+                self.len -= 1;
+                return Some(val);
+            }
+            let mut node = self.queue.pop_front()?;
+            self.queue.extend(node.right.take());
+            self.queue.extend(node.left.take());
+            self.values = node.into_values();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoLevelOrder<T> {}
+
+impl<T> FusedIterator for IntoLevelOrder<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{size, BinarySearchTree, Compare, DuplicatePolicy, Link, NaturalOrder};
+    use crate::data_structures::bst_tests::bst_tests;
+    use crate::sort::random_vec;
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+    use core::ops::Bound;
+
+    bst_tests!(BinarySearchTree);
+
+    /// 检查子树满足二叉搜索树的顺序（左子树不小于节点，右子树不大于节点），并按从小到大的顺序收集元素
+    fn check<T>(link: &Link<T>, lo: Option<&T>, hi: Option<&T>, result: &mut Vec<T>)
+    where
+        T: PartialOrd + Copy,
+    {
+        if let Some(node) = link {
+            assert_eq!(
+                node.size,
+                size(&node.left) + size(&node.right) + node.multiplicity()
+            );
+            assert!(lo.is_none_or(|lo| *lo <= node.item.val));
+            assert!(hi.is_none_or(|hi| node.item.val <= *hi));
+            assert!(node.item.duplicates.iter().all(|it| *it == node.item.val));
+            check(&node.right, lo, Some(&node.item.val), result);
+            result.extend(node.values());
+            check(&node.left, Some(&node.item.val), hi, result);
+        }
+    }
+
+    fn sorted_values<T>(tree: &BinarySearchTree<T>) -> Vec<T>
+    where
+        T: PartialOrd + Copy,
+    {
+        let mut result = Vec::new();
+        check(&tree.root, None, None, &mut result);
+        result
+    }
+
+    #[test]
+    fn test_remove_root() {
+        // 删除有两个子节点的根节点
+        let mut tree = BinarySearchTree::new();
+        for val in [5, 3, 8, 7, 9, 6] {
+            tree.insert(val);
+        }
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(tree.root.as_ref().map(|it| it.item.val), Some(6));
+        assert_eq!(sorted_values(&tree), [3, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_rank_and_select() {
+        let tree = prepare_tree();
+        assert_eq!(tree.rank(&0), 0);
+        assert_eq!(tree.rank(&1), 0);
+        assert_eq!(tree.rank(&4), 3);
+        assert_eq!(tree.rank(&8), 6);
+
+        assert_eq!(tree.select(0), Some(&1));
+        assert_eq!(tree.select(3), Some(&5));
+        assert_eq!(tree.select(5), Some(&7));
+        assert_eq!(tree.select(6), None);
+
+        assert_eq!(tree.count_range(&2, &6), 4);
+        assert_eq!(tree.count_range(&4, &4), 0);
+        assert_eq!(tree.count_range(&0, &100), 6);
+    }
+
+    #[test]
+    fn test_random_order_statistics() {
+        let mut tree = BinarySearchTree::new();
+        let mut expected = Vec::new();
+        let ops = random_vec(1000, 38);
+        for (idx, val) in random_vec(1000, 39).into_iter().enumerate() {
+            let val = val % 50;
+            if ops[idx].is_multiple_of(4) {
+                if let Some(pos) = expected.iter().position(|it| *it == val) {
+                    expected.remove(pos);
+                }
+                tree.remove(&val);
+            } else {
+                tree.insert(val);
+                expected.push(val);
+            }
+            expected.sort();
+
+            assert_eq!(tree.len(), expected.len());
+            assert_eq!(sorted_values(&tree), expected);
+            for k in 0..=expected.len() {
+                assert_eq!(tree.select(k), expected.get(k));
+            }
+            for val in 0..=50 {
+                let rank = expected.iter().filter(|it| **it < val).count();
+                assert_eq!(tree.rank(&val), rank);
+                let count = expected
+                    .iter()
+                    .filter(|it| (val..val + 10).contains(*it))
+                    .count();
+                assert_eq!(tree.count_range(&val, &(val + 9)), count);
+            }
+        }
+    }
+
+    fn shaped_tree() -> BinarySearchTree<i32> {
+        let mut tree = BinarySearchTree::new();
+        for val in [5, 3, 8, 1, 4, 7, 9] {
+            tree.insert(val);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_iter() {
+        let tree = shaped_tree();
+        assert!(tree.iter().eq(&[1, 3, 4, 5, 7, 8, 9]));
+        assert!(tree.iter().rev().eq(&[9, 8, 7, 5, 4, 3, 1]));
+        assert!((&tree).into_iter().eq(tree.iter()));
+
+        let mut iter = tree.iter();
+        assert_eq!(iter.len(), 7);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&9));
+        assert_eq!(iter.next_back(), Some(&8));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert!(iter.eq(&[4, 5, 7]));
+
+        let empty: BinarySearchTree<i32> = BinarySearchTree::new();
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(empty.into_iter().next_back(), None);
+    }
+
+    #[test]
+    fn test_traversal_order() {
+        let tree = shaped_tree();
+        assert!(tree.pre_order().eq(&[5, 3, 1, 4, 8, 7, 9]));
+        assert!(tree.post_order().eq(&[1, 4, 3, 7, 9, 8, 5]));
+        assert!(tree.level_order().eq(&[5, 3, 8, 1, 4, 7, 9]));
+        assert_eq!(tree.post_order().len(), 7);
+
+        assert!(shaped_tree().into_iter().eq([1, 3, 4, 5, 7, 8, 9]));
+        assert!(shaped_tree().into_pre_order().eq([5, 3, 1, 4, 8, 7, 9]));
+        assert!(shaped_tree().into_post_order().eq([1, 4, 3, 7, 9, 8, 5]));
+        assert!(shaped_tree().into_level_order().eq([5, 3, 8, 1, 4, 7, 9]));
+    }
+
+    #[test]
+    fn test_random_double_ended() {
+        let mut tree = BinarySearchTree::new();
+        let mut other = BinarySearchTree::new();
+        let mut expected = random_vec(500, 40);
+        for val in &expected {
+            tree.insert(*val);
+            other.insert(*val);
+        }
+        expected.sort();
+
+        let sides = random_vec(500, 41);
+        let mut iter = tree.iter();
+        let mut into_iter = other.into_iter();
+        let (mut low, mut high) = (0, expected.len());
+        for side in sides {
+            if side % 2 == 0 {
+                assert_eq!(iter.next(), Some(&expected[low]));
+                assert_eq!(into_iter.next(), Some(expected[low]));
+                low += 1;
+            } else {
+                high -= 1;
+                assert_eq!(iter.next_back(), Some(&expected[high]));
+                assert_eq!(into_iter.next_back(), Some(expected[high]));
+            }
+            assert_eq!(into_iter.len(), high - low);
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(into_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_range() {
+        let tree = shaped_tree();
+        assert!(tree.range(3..8).eq(&[3, 4, 5, 7]));
+        assert!(tree.range(3..=8).eq(&[3, 4, 5, 7, 8]));
+        assert!(tree.range(2..6).rev().eq(&[5, 4, 3]));
+        assert!(tree.range(..4).eq(&[1, 3]));
+        assert!(tree.range(6..).eq(&[7, 8, 9]));
+        assert!(tree.range(..).eq(tree.iter()));
+        assert!(tree
+            .range((Bound::Excluded(3), Bound::Excluded(5)))
+            .eq(&[4]));
+        assert_eq!(
+            tree.range((Bound::Included(8), Bound::Excluded(3))).next(),
+            None
+        );
+        assert_eq!(tree.range(10..).next(), None);
+        assert_eq!(tree.range(4..=7).len(), 3);
+    }
+
+    #[test]
+    fn test_random_range() {
+        let mut tree = BinarySearchTree::new();
+        let mut expected = random_vec(500, 42);
+        for val in &expected {
+            tree.insert(*val);
+        }
+        expected.sort();
+
+        for (lo, hi) in random_vec(100, 43).into_iter().zip(random_vec(100, 44)) {
+            let (lo, hi) = (lo.min(hi), lo.max(hi));
+            let values = expected.iter().filter(|it| (lo..hi).contains(*it));
+            assert!(tree.range(lo..hi).eq(values.clone()));
+            assert!(tree.range(lo..hi).rev().eq(values.rev()));
+        }
+    }
+
+    /// 只按 key 比较的记录，用于区分相等元素中的不同值
+    #[derive(Debug, Clone, Copy)]
+    struct Record {
+        key: i32,
+        tag: char,
+    }
+
+    impl PartialEq for Record {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl PartialOrd for Record {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.key.partial_cmp(&other.key)
+        }
+    }
+
+    fn record(key: i32, tag: char) -> Record {
+        Record { key, tag }
+    }
+
+    #[test]
+    fn test_duplicate_policy() {
+        let mut tree = BinarySearchTree::new();
+        assert_eq!(tree.policy(), DuplicatePolicy::Allow);
+        assert!(tree.insert(record(1, 'a')));
+        assert!(tree.insert(record(1, 'b')));
+        assert_eq!(tree.len(), 2);
+
+        let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Reject);
+        assert!(tree.insert(record(1, 'a')));
+        assert!(!tree.insert(record(1, 'b')));
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree.min().map(|it| it.tag), Some('a'));
+
+        let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Replace);
+        assert!(tree.insert(record(1, 'a')));
+        assert!(tree.insert(record(2, 'a')));
+        assert!(!tree.insert(record(1, 'b')));
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.min().map(|it| it.tag), Some('b'));
+
+        let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Count);
+        assert!(tree.insert(record(1, 'a')));
+        assert!(!tree.insert(record(1, 'b')));
+        assert!(!tree.insert(record(1, 'c')));
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.count(&record(1, ' ')), 3);
+        let tags: Vec<_> = tree.iter().map(|it| it.tag).collect();
+        assert_eq!(tags, ['a', 'b', 'c']);
+        let tags: Vec<_> = tree.into_iter().rev().map(|it| it.tag).collect();
+        assert_eq!(tags, ['c', 'b', 'a']);
+    }
+
+    #[test]
+    fn test_multiset() {
+        let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Count);
+        for val in [5, 3, 8, 3, 5, 5, 9] {
+            tree.insert(val);
+        }
+        assert_eq!(tree.len(), 7);
+        assert_eq!(sorted_values(&tree), [3, 3, 5, 5, 5, 8, 9]);
+        assert_eq!((tree.count(&5), tree.count(&3), tree.count(&4)), (3, 2, 0));
+
+        assert!(tree.iter().rev().eq(&[9, 8, 5, 5, 5, 3, 3]));
+        assert!(tree.range(4..9).eq(&[5, 5, 5, 8]));
+        assert_eq!(tree.range(3..=5).len(), 5);
+        assert!(tree.pre_order().eq(&[5, 5, 5, 3, 3, 8, 9]));
+        assert!(tree.post_order().eq(&[3, 3, 9, 8, 5, 5, 5]));
+        assert!(tree.level_order().eq(&[5, 5, 5, 3, 3, 8, 9]));
+        assert_eq!((tree.rank(&5), tree.rank(&8)), (2, 5));
+        let selected: Vec<_> = (0..7).map(|k| tree.select(k).copied()).collect();
+        assert_eq!(selected, [3, 3, 5, 5, 5, 8, 9].map(Some));
+
+        assert_eq!(tree.remove_one(&5), Some(5));
+        assert_eq!(tree.count(&5), 2);
+        assert_eq!(tree.remove_all(&5), 2);
+        assert_eq!(tree.remove_all(&5), 0);
+        assert_eq!(tree.remove_one(&5), None);
+        assert_eq!(sorted_values(&tree), [3, 3, 8, 9]);
+
+        tree.insert(8);
+        tree.retain(|it| *it != 3);
+        assert_eq!(sorted_values(&tree), [8, 8, 9]);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.pop_max(), Some(9));
+        assert_eq!(tree.pop_min(), Some(8));
+        assert_eq!(tree.count(&8), 1);
+        assert!(tree.into_pre_order().eq([8]));
+    }
+
+    #[test]
+    fn test_remove_all_separate_nodes() {
+        let mut tree = BinarySearchTree::new();
+        let mut expected: Vec<u64> = Vec::new();
+        let ops = random_vec(2000, 60);
+        for (idx, val) in random_vec(2000, 61).into_iter().enumerate() {
+            let val = val % 20;
+            match ops[idx] % 10 {
+                0 | 1 => {
+                    let pos = expected.iter().position(|it| *it == val);
+                    assert_eq!(tree.remove_one(&val), pos.map(|pos| expected.remove(pos)));
+                }
+                2 => {
+                    let count = expected.iter().filter(|it| **it == val).count();
+                    expected.retain(|it| *it != val);
+                    assert_eq!(tree.remove_all(&val), count);
+                    assert!(!tree.search(&val));
+                }
+                _ => {
+                    tree.insert(val);
+                    expected.push(val);
+                }
+            }
+
+            expected.sort();
+            assert_eq!(sorted_values(&tree), expected);
+        }
+    }
+
+    #[test]
+    fn test_random_multiset() {
+        let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Count);
+        let mut expected: Vec<u64> = Vec::new();
+        let ops = random_vec(2000, 46);
+        for (idx, val) in random_vec(2000, 47).into_iter().enumerate() {
+            let val = val % 50;
+            match ops[idx] % 5 {
+                0 => {
+                    let pos = expected.iter().position(|it| *it == val);
+                    assert_eq!(tree.remove_one(&val), pos.map(|pos| expected.remove(pos)));
+                }
+                1 if ops[idx].is_multiple_of(7) => {
+                    let count = expected.iter().filter(|it| **it == val).count();
+                    expected.retain(|it| *it != val);
+                    assert_eq!(tree.remove_all(&val), count);
+                }
+                _ => {
+                    let is_new = !expected.contains(&val);
+                    assert_eq!(tree.insert(val), is_new);
+                    expected.push(val);
+                }
+            }
+
+            expected.sort();
+            assert_eq!(sorted_values(&tree), expected);
+            assert_eq!(
+                tree.count(&val),
+                expected.iter().filter(|it| **it == val).count()
+            );
+        }
+        assert!(tree.iter().rev().eq(expected.iter().rev()));
+
+        let mut into_iter = tree.into_iter();
+        let (mut low, mut high) = (0, expected.len());
+        for side in random_vec(expected.len(), 48) {
+            if side % 2 == 0 {
+                assert_eq!(into_iter.next(), Some(expected[low]));
+                low += 1;
+            } else {
+                high -= 1;
+                assert_eq!(into_iter.next_back(), Some(expected[high]));
+            }
+        }
+        assert_eq!(into_iter.next(), None);
+    }
+
+    /// 没有实现 PartialOrd 的记录，只能通过比较器排序
+    #[derive(Debug, PartialEq)]
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    fn by_id(a: &User, b: &User) -> Option<Ordering> {
+        a.id.partial_cmp(&b.id)
+    }
+
+    #[test]
+    fn test_custom_comparator() {
+        let mut tree = BinarySearchTree::with_comparator(by_id);
+        for (id, name) in [(3, "c"), (1, "a"), (2, "b")] {
+            tree.insert(User { id, name });
+        }
+        let names: Vec<_> = tree.iter().map(|it| it.name).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert!(tree.search(&User { id: 2, name: "" }));
+        assert_eq!(tree.rank(&User { id: 3, name: "" }), 2);
+        assert_eq!(
+            tree.remove(&User { id: 1, name: "" }).map(|it| it.name),
+            Some("a")
+        );
+        assert_eq!(tree.min().map(|it| it.id), Some(2));
+
+        let mut tree = BinarySearchTree::with_comparator_and_policy(
+            |a: &&str, b: &&str| a.to_lowercase().partial_cmp(&b.to_lowercase()),
+            DuplicatePolicy::Count,
+        );
+        for val in ["b", "A", "a", "C", "B"] {
+            tree.insert(val);
+        }
+        assert!(tree.iter().eq(&["A", "a", "b", "B", "C"]));
+        assert_eq!(tree.count(&"B"), 2);
+        assert!(tree.range("a".."c").eq(&["A", "a", "b", "B"]));
+        assert_eq!(tree.floor(&"bb"), Some(&"b"));
+        assert_eq!(tree.ceil(&"bb"), Some(&"C"));
+    }
+
+    #[test]
+    fn test_random_reverse_comparator() {
+        let reverse: Box<dyn Compare<u64>> = Box::new(|a: &u64, b: &u64| b.partial_cmp(a));
+        let mut tree = BinarySearchTree::with_comparator(reverse);
+        let mut expected = Vec::new();
+        let ops = random_vec(1000, 50);
+        for (idx, val) in random_vec(1000, 51).into_iter().enumerate() {
+            if ops[idx].is_multiple_of(3) {
+                let pos = expected.iter().position(|it| *it == val);
+                assert_eq!(tree.remove(&val), pos.map(|pos| expected.remove(pos)));
+            } else {
+                tree.insert(val);
+                expected.push(val);
+            }
+        }
+        expected.sort_by(|a, b| b.cmp(a));
+        assert!(tree.iter().eq(expected.iter()));
+        assert_eq!(tree.min(), expected.first());
+        assert_eq!(tree.max(), expected.last());
+        for (k, val) in expected.iter().enumerate() {
+            assert_eq!(tree.select(k), Some(val));
+        }
+
+        let tree: BinarySearchTree<u64, NaturalOrder> = BinarySearchTree::default();
+        assert!(tree.is_empty());
+    }
+
+    /// 按从小到大的顺序逐个插入 0, 2, 4, ... 得到的退化树，每个节点只有较大的子节点
+    ///
+    /// 逐个插入需要 O(n^2) 的时间，n 不宜过大。
+    fn sorted_chain(n: u64) -> BinarySearchTree<u64> {
+        let mut tree = BinarySearchTree::new();
+        for val in 0..n {
+            tree.insert(val * 2);
+        }
+        tree
+    }
+
+    /// 在栈空间很小的线程中运行，递归深度与树高成正比的实现会在这里栈溢出
+    #[cfg(feature = "std")]
+    fn with_small_stack<F>(f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(f)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_deep_tree() {
+        with_small_stack(|| {
+            let n = 5_000;
+            let mut tree = sorted_chain(n);
+            // 每个节点只有较大的子节点时，前序遍历与中序遍历的顺序相同
+            assert!(tree.pre_order().eq(tree.iter()));
+            assert!(tree.insert(2 * n));
+            assert!(tree.search(&n));
+            assert!(!tree.search(&(n + 1)));
+            assert_eq!(tree.max(), Some(&(2 * n)));
+            assert_eq!(tree.min(), Some(&0));
+            assert_eq!(tree.floor(&(n + 1)), Some(&n));
+            assert_eq!(tree.ceil(&(n + 1)), Some(&(n + 2)));
+            assert_eq!(tree.rank(&(2 * n)), n as usize);
+            assert_eq!(tree.select(n as usize - 1), Some(&(2 * n - 2)));
+            assert_eq!(tree.count_range(&10, &19), 5);
+            assert!(tree.range(2 * n - 4..).eq(&[2 * n - 4, 2 * n - 2, 2 * n]));
+            assert_eq!(tree.iter().rev().count(), n as usize + 1);
+            assert_eq!(tree.pre_order().count(), n as usize + 1);
+            assert_eq!(tree.post_order().count(), n as usize + 1);
+            assert_eq!(tree.level_order().count(), n as usize + 1);
+
+            assert_eq!(tree.remove(&(2 * n - 2)), Some(2 * n - 2));
+            assert_eq!(tree.remove(&(2 * n - 2)), None);
+            assert_eq!(tree.pop_max(), Some(2 * n));
+            assert_eq!(tree.pop_min(), Some(0));
+            assert_eq!(tree.len(), n as usize - 2);
+            assert_eq!(tree.count(&n), 1);
+            drop(tree);
+
+            let mut into_iter = sorted_chain(n).into_iter();
+            assert_eq!(into_iter.next(), Some(0));
+            assert_eq!(into_iter.next_back(), Some(2 * n - 2));
+            drop(into_iter);
+            assert_eq!(sorted_chain(n).into_pre_order().next(), Some(0));
+            assert_eq!(sorted_chain(n).into_post_order().next(), Some(2 * n - 2));
+            assert_eq!(sorted_chain(n).into_level_order().next(), Some(0));
+
+            let mut tree = sorted_chain(n);
+            tree.retain(|it| it % 4 == 0);
+            assert_eq!(tree.len(), n as usize / 2);
+            assert_eq!(tree.iter().count(), n as usize / 2);
+        });
+    }
+
+    fn height<T>(link: &Link<T>) -> usize {
+        link.as_ref()
+            .map_or(0, |node| height(&node.left).max(height(&node.right)) + 1)
+    }
+
+    /// 有 n 个节点的完全平衡二叉树的高度
+    fn balanced_height(n: usize) -> usize {
+        (usize::BITS - n.leading_zeros()) as usize
+    }
+
+    #[test]
+    fn test_from_sorted() {
+        for n in 0..100 {
+            let tree = BinarySearchTree::from_sorted((0..n).collect());
+            assert_eq!(sorted_values(&tree), (0..n).collect::<Vec<_>>());
+            assert_eq!(height(&tree.root), balanced_height(n));
+        }
+
+        let tree = BinarySearchTree::from_sorted(vec![1, 2, 2, 2, 3]);
+        assert_eq!(sorted_values(&tree), [1, 2, 2, 2, 3]);
+        assert_eq!(tree.count(&2), 3);
+        assert_eq!(tree.rank(&3), 4);
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut expected = random_vec(1000, 52);
+        let mut tree: BinarySearchTree<_> = expected.iter().copied().collect();
+        expected.sort();
+        assert_eq!(sorted_values(&tree), expected);
+        assert_eq!(height(&tree.root), balanced_height(1000));
+
+        tree.extend(random_vec(1000, 53));
+        expected.extend(random_vec(1000, 53));
+        expected.sort();
+        assert_eq!(sorted_values(&tree), expected);
+
+        let mut tree = BinarySearchTree::new();
+        tree.extend(0..1023);
+        assert_eq!(height(&tree.root), 10);
+
+        let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Count);
+        tree.extend([3, 1, 3, 2, 3, 1]);
+        assert_eq!(sorted_values(&tree), [1, 1, 2, 3, 3, 3]);
+        assert_eq!(tree.pre_order().count(), 6);
+        assert_eq!((tree.count(&1), tree.count(&3)), (2, 3));
+
+        let mut tree: BinarySearchTree<String> = ["pear", "apple", "fig"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        tree.extend(["kiwi", "apple"].map(String::from));
+        assert!(tree
+            .iter()
+            .map(String::as_str)
+            .eq(["apple", "apple", "fig", "kiwi", "pear"]));
+    }
+
+    #[test]
+    fn test_rebalance() {
+        for n in 0..100 {
+            let mut tree = BinarySearchTree::new();
+            let mut expected = random_vec(n, 54 + n as u64);
+            for val in &expected {
+                tree.insert(*val);
+            }
+            tree.rebalance();
+            expected.sort();
+            assert_eq!(sorted_values(&tree), expected);
+            assert_eq!(height(&tree.root), balanced_height(n));
+        }
+
+        let mut tree = BinarySearchTree::with_policy(DuplicatePolicy::Count);
+        for val in [5, 1, 5, 4, 2, 5, 3] {
+            tree.insert(val);
+        }
+        tree.rebalance();
+        assert_eq!(sorted_values(&tree), [1, 2, 3, 4, 5, 5, 5]);
+        assert_eq!(height(&tree.root), balanced_height(5));
+        assert_eq!(tree.select(4), Some(&5));
+
+        let n = 10_000;
+        let mut tree = sorted_chain(n);
+        tree.rebalance();
+        assert_eq!(height(&tree.root), balanced_height(n as usize));
+        assert_eq!(sorted_values(&tree).len(), n as usize);
+        assert_eq!(tree.select(1234), Some(&2468));
     }
 }
@@ -0,0 +1,1569 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug};
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::{Bound, Index, RangeBounds};
+
+use super::bst_node::{
+    self, build, count_below, drop_link, size, take, take_max, take_min, IntoNodes, Item, Nodes,
+};
+
+/// 节点中的键值对，节点结构与 BinarySearchTree 相同：左子树中的键大于节点的键，右子树中的键小于节点的键
+struct Pair<K, V> {
+    key: K,
+    val: V,
+}
+
+impl<K, V> Pair<K, V> {
+    fn into_tuple(self) -> (K, V) {
+        (self.key, self.val)
+    }
+}
+
+impl<K, V> Item for Pair<K, V> {
+    fn multiplicity(&self) -> usize {
+        1
+    }
+}
+
+type Node<K, V> = bst_node::Node<Pair<K, V>>;
+
+type Link<K, V> = bst_node::Link<Pair<K, V>>;
+
+fn find<'a, K, V, Q>(mut link: &'a Link<K, V>, key: &Q) -> Option<&'a Node<K, V>>
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    while let Some(node) = link {
+        match node.item.key.borrow().cmp(key) {
+            Ordering::Less => link = &node.left,
+            Ordering::Greater => link = &node.right,
+            Ordering::Equal => return Some(node),
+        }
+    }
+    None
+}
+
+fn find_mut<'a, K, V, Q>(mut link: &'a mut Link<K, V>, key: &Q) -> Option<&'a mut Node<K, V>>
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    while let Some(node) = link {
+        match node.item.key.borrow().cmp(key) {
+            Ordering::Less => link = &mut node.left,
+            Ordering::Greater => link = &mut node.right,
+            Ordering::Equal => return Some(node),
+        }
+    }
+    None
+}
+
+/// 判断键是否小于范围的起点
+fn below<K, Q, R>(range: &R, key: &K) -> bool
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+{
+    match range.start_bound() {
+        Bound::Included(lo) => key.borrow() < lo,
+        Bound::Excluded(lo) => key.borrow() <= lo,
+        Bound::Unbounded => false,
+    }
+}
+
+/// 判断键是否大于范围的终点
+fn above<K, Q, R>(range: &R, key: &K) -> bool
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+{
+    match range.end_bound() {
+        Bound::Included(hi) => key.borrow() > hi,
+        Bound::Excluded(hi) => key.borrow() >= hi,
+        Bound::Unbounded => false,
+    }
+}
+
+/// 基于二叉搜索树的有序映射
+///
+/// 接口与 `BTreeMap` 保持一致，未做平衡处理，各项操作的时间复杂度为 O(h)，h 为树的高度。
+pub struct BstMap<K, V> {
+    root: Link<K, V>,
+}
+
+impl<K, V> BstMap<K, V> {
+    /// 创建一个空的映射
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let map: BstMap<i32, &str> = BstMap::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// 获取映射中键值对的数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// 判断映射是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let map: BstMap<i32, &str> = BstMap::new();
+    ///
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 删除所有键值对
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    /// map.clear();
+    ///
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        drop_link(self.root.take());
+    }
+
+    /// 获取键最小的键值对
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.first_key_value(), Some((&1, &"a")));
+    /// ```
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some((&node.item.key, &node.item.val))
+    }
+
+    /// 获取键最大的键值对
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.last_key_value(), Some((&2, &"b")));
+    /// ```
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some((&node.item.key, &node.item.val))
+    }
+
+    /// 删除并返回键最小的键值对
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.pop_first(), Some((1, "a")));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        take_min(&mut self.root).map(|node| node.item.into_tuple())
+    }
+
+    /// 删除并返回键最大的键值对
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.pop_last(), Some((2, "b")));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        take_max(&mut self.root).map(|node| node.item.into_tuple())
+    }
+
+    /// 获取按键从小到大的顺序迭代键值对的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert!(map.iter().eq([(&1, &"a"), (&2, &"b")]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            nodes: Nodes::new(&self.root),
+            len: self.len(),
+        }
+    }
+
+    /// 获取按键从小到大的顺序迭代键值对的迭代器，值为可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, 20);
+    /// map.insert(1, 10);
+    ///
+    /// for (_, val) in map.iter_mut() {
+    ///     *val += 1;
+    /// }
+    /// assert_eq!(map[&1], 11);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let mut iter = IterMut {
+            front: VecDeque::new(),
+            back: VecDeque::new(),
+            len: self.len(),
+        };
+        iter.push_front(self.root.as_mut());
+        iter
+    }
+
+    /// 获取按从小到大的顺序迭代所有键的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert!(map.keys().eq(&[1, 2]));
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys(self.iter())
+    }
+
+    /// 获取按键从小到大的顺序迭代所有值的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert!(map.values().eq(&["a", "b"]));
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values(self.iter())
+    }
+
+    /// 获取按键从小到大的顺序迭代所有值的可变引用的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, String::from("a"));
+    ///
+    /// for val in map.values_mut() {
+    ///     val.push('!');
+    /// }
+    /// assert_eq!(map[&1], "a!");
+    /// ```
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut(self.iter_mut())
+    }
+}
+
+impl<K, V> BstMap<K, V>
+where
+    K: Ord,
+{
+    /// 插入键值对，键已存在时更新值并返回旧值，键本身不会被更新
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    ///
+    /// assert_eq!(map.insert(1, "a"), None);
+    /// assert_eq!(map.insert(1, "b"), Some("a"));
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        match find_mut(&mut self.root, &key) {
+            Some(node) => Some(mem::replace(&mut node.item.val, val)),
+            None => {
+                self.insert_new(key, val);
+                None
+            }
+        }
+    }
+
+    /// 插入映射中不存在的键，返回值的可变引用
+    fn insert_new(&mut self, key: K, val: V) -> &mut V {
+        let mut link = &mut self.root;
+        while link.is_some() {
+            let node = link.as_mut().unwrap();
+            node.size += 1;
+            link = if node.item.key < key {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+        &mut link.insert(Box::new(Node::new(Pair { key, val }))).item.val
+    }
+
+    /// 获取键对应的值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        find(&self.root, key).map(|node| &node.item.val)
+    }
+
+    /// 获取键以及键对应的值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.get_key_value(&1), Some((&1, &"a")));
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        find(&self.root, key).map(|node| (&node.item.key, &node.item.val))
+    }
+
+    /// 获取键对应的值的可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// if let Some(val) = map.get_mut(&1) {
+    ///     *val = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        find_mut(&mut self.root, key).map(|node| &mut node.item.val)
+    }
+
+    /// 判断映射中是否存在指定的键
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// assert!(map.contains_key(&1));
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        find(&self.root, key).is_some()
+    }
+
+    /// 删除键对应的键值对，返回被删除的值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.remove(&1), Some("a"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, val)| val)
+    }
+
+    /// 删除键对应的键值对，返回被删除的键值对
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
+    /// ```
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        take(&mut self.root, |it| Some(it.key.borrow().cmp(key))).map(|node| node.item.into_tuple())
+    }
+
+    /// 获取键不大于指定键的键值对中键最大的一个
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(3, "c");
+    ///
+    /// assert_eq!(map.floor_entry(&2), Some((&1, &"a")));
+    /// assert_eq!(map.floor_entry(&0), None);
+    /// ```
+    pub fn floor_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.item.key.borrow().cmp(key) {
+                Ordering::Greater => link = &node.right,
+                Ordering::Less => {
+                    result = Some((&node.item.key, &node.item.val));
+                    link = &node.left;
+                }
+                Ordering::Equal => return Some((&node.item.key, &node.item.val)),
+            }
+        }
+        result
+    }
+
+    /// 获取键不小于指定键的键值对中键最小的一个
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(3, "c");
+    ///
+    /// assert_eq!(map.ceil_entry(&2), Some((&3, &"c")));
+    /// assert_eq!(map.ceil_entry(&4), None);
+    /// ```
+    pub fn ceil_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.item.key.borrow().cmp(key) {
+                Ordering::Less => link = &node.left,
+                Ordering::Greater => {
+                    result = Some((&node.item.key, &node.item.val));
+                    link = &node.right;
+                }
+                Ordering::Equal => return Some((&node.item.key, &node.item.val)),
+            }
+        }
+        result
+    }
+
+    /// 获取键对应的条目，用于原地查询、插入或修改
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut count = BstMap::new();
+    /// for word in ["a", "b", "a"] {
+    ///     count.entry(word).and_modify(|it| *it += 1).or_insert(1);
+    /// }
+    ///
+    /// assert_eq!(count[&"a"], 2);
+    /// assert_eq!(count[&"b"], 1);
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        if find(&self.root, &key).is_some() {
+            Entry::Occupied(OccupiedEntry { map: self, key })
+        } else {
+            Entry::Vacant(VacantEntry { map: self, key })
+        }
+    }
+
+    /// 获取按键从小到大的顺序迭代指定范围内键值对的迭代器，范围外的子树不会被访问
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// for (key, val) in [(1, "a"), (2, "b"), (3, "c"), (4, "d")] {
+    ///     map.insert(key, val);
+    /// }
+    ///
+    /// assert!(map.range(2..4).eq([(&2, &"b"), (&3, &"c")]));
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let is_below = |it: &Pair<K, V>| below(&range, &it.key);
+        let is_above = |it: &Pair<K, V>| above(&range, &it.key);
+        let up_to_end = count_below(&self.root, |it| !is_above(it));
+        Iter {
+            len: up_to_end.saturating_sub(count_below(&self.root, is_below)),
+            nodes: Nodes::range(&self.root, is_below, is_above),
+        }
+    }
+
+    /// 获取按键从小到大的顺序迭代指定范围内键值对的迭代器，值为可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// for key in 1..=4 {
+    ///     map.insert(key, 0);
+    /// }
+    ///
+    /// for (_, val) in map.range_mut(2..) {
+    ///     *val += 1;
+    /// }
+    /// assert!(map.values().eq(&[0, 1, 1, 1]));
+    /// ```
+    pub fn range_mut<Q, R>(&mut self, range: R) -> IterMut<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let up_to_end = count_below(&self.root, |it| !above(&range, &it.key));
+        let mut iter = IterMut {
+            front: VecDeque::new(),
+            back: VecDeque::new(),
+            len: up_to_end.saturating_sub(count_below(&self.root, |it| below(&range, &it.key))),
+        };
+
+        // 找到第一个位于范围内的节点，范围内的其它节点都在它的两棵子树中
+        let mut link = self.root.as_mut();
+        while let Some(node) = link {
+            let Node {
+                item: Pair { key, val },
+                left,
+                right,
+                ..
+            } = &mut **node;
+            if below(&range, key) {
+                link = left.as_mut();
+            } else if above(&range, key) {
+                link = right.as_mut();
+            } else {
+                iter.front.push_back((key, val, None));
+
+                let mut link = right.as_mut();
+                while let Some(node) = link {
+                    let Node {
+                        item: Pair { key, val },
+                        left,
+                        right,
+                        ..
+                    } = &mut **node;
+                    if below(&range, key) {
+                        link = left.as_mut();
+                    } else {
+                        iter.front.push_back((key, val, left.as_mut()));
+                        link = right.as_mut();
+                    }
+                }
+
+                let mut link = left.as_mut();
+                while let Some(node) = link {
+                    let Node {
+                        item: Pair { key, val },
+                        left,
+                        right,
+                        ..
+                    } = &mut **node;
+                    if above(&range, key) {
+                        link = right.as_mut();
+                    } else {
+                        iter.back.push_back((key, val, right.as_mut()));
+                        link = left.as_mut();
+                    }
+                }
+                break;
+            }
+        }
+        iter
+    }
+}
+
+impl<K, V> Default for BstMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Clone for BstMap<K, V>
+where
+    K: Clone,
+    V: Clone,
+{
+    /// 按键的顺序复制所有键值对，复制出的映射是一棵平衡的树
+    fn clone(&self) -> Self {
+        let mut nodes = self.iter().map(|(key, val)| {
+            Box::new(Node::new(Pair {
+                key: key.clone(),
+                val: val.clone(),
+            }))
+        });
+        Self {
+            root: build(&mut nodes, self.len()),
+        }
+    }
+}
+
+impl<K, V> PartialEq for BstMap<K, V>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K, V> Eq for BstMap<K, V>
+where
+    K: Eq,
+    V: Eq,
+{
+}
+
+impl<K, V> Drop for BstMap<K, V> {
+    fn drop(&mut self) {
+        drop_link(self.root.take());
+    }
+}
+
+impl<K, V> Debug for BstMap<K, V>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, Q> Index<&Q> for BstMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    /// 获取键对应的值，键不存在时 panic
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for BstMap<K, V>
+where
+    K: Ord,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = BstMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V> Extend<(K, V)> for BstMap<K, V>
+where
+    K: Ord,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        for (key, val) in iter {
+            self.insert(key, val);
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a BstMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut BstMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, V> IntoIterator for BstMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// 获取按键从小到大的顺序取出所有键值对的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// assert_eq!(map.into_iter().collect::<Vec<_>>(), [(1, "a"), (2, "b")]);
+    /// ```
+    fn into_iter(mut self) -> IntoIter<K, V> {
+        IntoIter {
+            len: self.len(),
+            nodes: IntoNodes::new(self.root.take()),
+        }
+    }
+}
+
+/// 映射中的一个条目，可能存在也可能不存在
+pub enum Entry<'a, K, V> {
+    Vacant(VacantEntry<'a, K, V>),
+    Occupied(OccupiedEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V>
+where
+    K: Ord,
+{
+    /// 获取条目的键
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map: BstMap<i32, &str> = BstMap::new();
+    ///
+    /// assert_eq!(map.entry(1).key(), &1);
+    /// ```
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        }
+    }
+
+    /// 键不存在时插入指定值，返回值的可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    ///
+    /// *map.entry(1).or_insert(0) += 10;
+    /// *map.entry(1).or_insert(0) += 10;
+    /// assert_eq!(map[&1], 20);
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// 键不存在时插入函数返回的值，返回值的可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    ///
+    /// map.entry(1).or_insert_with(Vec::new).push("a");
+    /// assert_eq!(map[&1], ["a"]);
+    /// ```
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        self.or_insert_with_key(|_| default())
+    }
+
+    /// 键不存在时插入以键为参数的函数返回的值，返回值的可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    ///
+    /// map.entry(3).or_insert_with_key(|key| key * 2);
+    /// assert_eq!(map[&3], 6);
+    /// ```
+    pub fn or_insert_with_key<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce(&K) -> V,
+    {
+        match self {
+            Entry::Vacant(entry) => {
+                let val = default(entry.key());
+                entry.insert(val)
+            }
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// 键不存在时插入默认值，返回值的可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map: BstMap<i32, i32> = BstMap::new();
+    ///
+    /// assert_eq!(*map.entry(1).or_default(), 0);
+    /// ```
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// 键存在时修改其值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::BstMap;
+    /// let mut map = BstMap::new();
+    /// map.insert(1, 1);
+    ///
+    /// map.entry(1).and_modify(|it| *it += 1);
+    /// map.entry(2).and_modify(|it| *it += 1);
+    /// assert_eq!(map[&1], 2);
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// 映射中不存在的条目
+pub struct VacantEntry<'a, K, V> {
+    map: &'a mut BstMap<K, V>,
+    key: K,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V>
+where
+    K: Ord,
+{
+    /// 获取条目的键
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// 取回条目的键
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// 插入条目的值，返回值的可变引用
+    pub fn insert(self, val: V) -> &'a mut V {
+        self.map.insert_new(self.key, val)
+    }
+}
+
+/// 映射中已存在的条目
+///
+/// 条目只保存映射和键，每次访问都会重新查找键所在的节点，时间复杂度为 O(h)。
+pub struct OccupiedEntry<'a, K, V> {
+    map: &'a mut BstMap<K, V>,
+    key: K,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V>
+where
+    K: Ord,
+{
+    fn node(&self) -> &Node<K, V> {
+        find(&self.map.root, &self.key).unwrap()
+    }
+
+    fn node_mut(&mut self) -> &mut Node<K, V> {
+        find_mut(&mut self.map.root, &self.key).unwrap()
+    }
+
+    /// 获取条目的键
+    pub fn key(&self) -> &K {
+        &self.node().item.key
+    }
+
+    /// 获取条目的值
+    pub fn get(&self) -> &V {
+        &self.node().item.val
+    }
+
+    /// 获取条目的值的可变引用
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.node_mut().item.val
+    }
+
+    /// 转换为生命周期与映射相同的值的可变引用
+    pub fn into_mut(self) -> &'a mut V {
+        &mut find_mut(&mut self.map.root, &self.key).unwrap().item.val
+    }
+
+    /// 更新条目的值，返回旧值
+    pub fn insert(&mut self, val: V) -> V {
+        mem::replace(self.get_mut(), val)
+    }
+
+    /// 从映射中删除条目，返回条目的值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{BstMap, Entry};
+    /// let mut map = BstMap::new();
+    /// map.insert(1, "a");
+    ///
+    /// if let Entry::Occupied(entry) = map.entry(1) {
+    ///     assert_eq!(entry.remove(), "a");
+    /// }
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// 从映射中删除条目，返回映射中的键和条目的值
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.remove_entry(&self.key).unwrap()
+    }
+}
+
+/// 按键的顺序迭代键值对的迭代器
+///
+/// 与 BinarySearchTree 的中序遍历迭代器相同，节点由 `Nodes` 从两端依次给出，通过剩余数量判断两端是否相遇。
+pub struct Iter<'a, K, V> {
+    nodes: Nodes<'a, Pair<K, V>>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.nodes.next_front()?;
+        self.len -= 1;
+        Some((&node.item.key, &node.item.val))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.nodes.next_back()?;
+        self.len -= 1;
+        Some((&node.item.key, &node.item.val))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// 栈中的节点：节点的键、值的可变引用以及节点尚未访问的一棵子树
+type SplitNode<'a, K, V> = (&'a K, &'a mut V, Option<&'a mut Box<Node<K, V>>>);
+
+/// 按键的顺序迭代键值对的迭代器，值为可变引用
+///
+/// 可变引用不能同时出现在两个栈中，因此将节点拆分为键、值和子树三个互不重叠的引用：
+/// front 栈中的节点保留较大的子树，back 栈中的节点保留较小的子树，
+/// 一端的栈为空时，从另一端栈底的节点中拆出剩余的子树，两个栈都用 VecDeque 保存以便从栈底取出节点。
+pub struct IterMut<'a, K, V> {
+    front: VecDeque<SplitNode<'a, K, V>>,
+    back: VecDeque<SplitNode<'a, K, V>>,
+    len: usize,
+}
+
+impl<'a, K, V> IterMut<'a, K, V> {
+    fn push_front(&mut self, mut link: Option<&'a mut Box<Node<K, V>>>) {
+        while let Some(node) = link {
+            let Node {
+                item: Pair { key, val },
+                left,
+                right,
+                ..
+            } = &mut **node;
+            self.front.push_back((key, val, left.as_mut()));
+            link = right.as_mut();
+        }
+    }
+
+    fn push_back(&mut self, mut link: Option<&'a mut Box<Node<K, V>>>) {
+        while let Some(node) = link {
+            let Node {
+                item: Pair { key, val },
+                left,
+                right,
+                ..
+            } = &mut **node;
+            self.back.push_back((key, val, right.as_mut()));
+            link = left.as_mut();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        if self.front.is_empty() {
+            match self.back.front_mut()?.2.take() {
+                Some(node) => self.push_front(Some(node)),
+                None => {
+                    let (key, val, _) = self.back.pop_front()?;
+                    return Some((key, val));
+                }
+            }
+        }
+        let (key, val, greater) = self.front.pop_back()?;
+        self.push_front(greater);
+        Some((key, val))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        if self.back.is_empty() {
+            match self.front.front_mut()?.2.take() {
+                Some(node) => self.push_back(Some(node)),
+                None => {
+                    let (key, val, _) = self.front.pop_front()?;
+                    return Some((key, val));
+                }
+            }
+        }
+        let (key, val, smaller) = self.back.pop_back()?;
+        self.push_back(smaller);
+        Some((key, val))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// 按键的顺序取出所有键值对的迭代器
+pub struct IntoIter<K, V> {
+    nodes: IntoNodes<Pair<K, V>>,
+    len: usize,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.nodes.next_front().map(|node| node.item.into_tuple())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.nodes.next_back().map(|node| node.item.into_tuple())
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+/// 按顺序迭代所有键的迭代器
+pub struct Keys<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+/// 按键的顺序迭代所有值的迭代器
+pub struct Values<'a, K, V>(Iter<'a, K, V>);
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, val)| val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, val)| val)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+/// 按键的顺序迭代所有值的可变引用的迭代器
+pub struct ValuesMut<'a, K, V>(IterMut<'a, K, V>);
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, val)| val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ValuesMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, val)| val)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for ValuesMut<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::random_vec;
+    use alloc::collections::BTreeMap;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+
+    /// 检查子树的键有序且节点数量正确
+    fn check<K, V>(link: &Link<K, V>, lo: Option<&K>, hi: Option<&K>)
+    where
+        K: Ord,
+    {
+        if let Some(node) = link {
+            assert_eq!(node.size, size(&node.left) + size(&node.right) + 1);
+            assert!(lo.is_none_or(|lo| *lo < node.item.key));
+            assert!(hi.is_none_or(|hi| node.item.key < *hi));
+            check(&node.right, lo, Some(&node.item.key));
+            check(&node.left, Some(&node.item.key), hi);
+        }
+    }
+
+    fn prepare_map() -> BstMap<i32, String> {
+        [5, 3, 8, 1, 4, 7, 9]
+            .into_iter()
+            .map(|key| (key, key.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let mut map: BstMap<i32, i32> = BstMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.remove(&1), None);
+        assert_eq!(map.first_key_value(), None);
+        assert_eq!(map.pop_last(), None);
+        assert_eq!(map.iter().next(), None);
+        assert_eq!(map.iter_mut().next_back(), None);
+        assert_eq!(map.range_mut(1..).next(), None);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut map = prepare_map();
+        assert_eq!(map.len(), 7);
+        assert_eq!(map.insert(4, String::from("four")), Some(String::from("4")));
+        assert_eq!(map.len(), 7);
+        assert_eq!(map.get(&4).map(String::as_str), Some("four"));
+
+        assert_eq!(map.remove(&5), Some(String::from("5")));
+        assert_eq!(map.remove(&5), None);
+        assert_eq!(map.remove_entry(&1), Some((1, String::from("1"))));
+        check(&map.root, None, None);
+        assert!(map.keys().eq(&[3, 4, 7, 8, 9]));
+
+        assert_eq!(map.pop_first(), Some((3, String::from("3"))));
+        assert_eq!(map.pop_last(), Some((9, String::from("9"))));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn first_last_floor_ceil() {
+        let map = prepare_map();
+        assert_eq!(map.first_key_value().map(|it| *it.0), Some(1));
+        assert_eq!(map.last_key_value().map(|it| *it.0), Some(9));
+        assert_eq!(map.floor_entry(&6).map(|it| *it.0), Some(5));
+        assert_eq!(map.floor_entry(&7).map(|it| *it.0), Some(7));
+        assert_eq!(map.floor_entry(&0), None);
+        assert_eq!(map.ceil_entry(&6).map(|it| *it.0), Some(7));
+        assert_eq!(map.ceil_entry(&10), None);
+    }
+
+    #[test]
+    fn entry() {
+        let mut map: BstMap<&str, usize> = BstMap::new();
+        for word in "a b c a b a".split(' ') {
+            *map.entry(word).or_default() += 1;
+        }
+        assert_eq!(format!("{:?}", map), r#"{"a": 3, "b": 2, "c": 1}"#);
+
+        match map.entry("b") {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.insert(10), 2);
+                assert_eq!(entry.get(), &10);
+            }
+            Entry::Vacant(_) => unreachable!(),
+        }
+        match map.entry("d") {
+            Entry::Vacant(entry) => assert_eq!(entry.into_key(), "d"),
+            Entry::Occupied(_) => unreachable!(),
+        }
+        assert!(!map.contains_key("d"));
+        check(&map.root, None, None);
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        let mut map = BstMap::new();
+        map.insert(String::from("b"), 2);
+        map.insert(String::from("a"), 1);
+        assert_eq!(map["a"], 1);
+        assert_eq!(map.get("b"), Some(&2));
+        assert!(map
+            .range::<str, _>((Bound::Included("a"), Bound::Excluded("b")))
+            .eq([(&String::from("a"), &1)]));
+    }
+
+    #[test]
+    fn range_mut() {
+        let mut map = prepare_map();
+        for (key, val) in map.range_mut(3..8) {
+            val.push_str(&key.to_string());
+        }
+        assert!(map.values().eq(["1", "33", "44", "55", "77", "8", "9"]));
+
+        let mut iter = map.range_mut(2..=8);
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next_back().map(|it| *it.0), Some(8));
+        assert_eq!(iter.next().map(|it| *it.0), Some(3));
+        assert!(iter.rev().map(|it| *it.0).eq([7, 5, 4]));
+    }
+
+    #[test]
+    fn occupied_entry_remove() {
+        let mut map = prepare_map();
+        match map.entry(5) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), (5, String::from("5"))),
+            Entry::Vacant(_) => unreachable!(),
+        }
+        match map.entry(1) {
+            Entry::Occupied(entry) => {
+                assert_eq!(entry.key(), &1);
+                assert_eq!(entry.remove(), "1");
+            }
+            Entry::Vacant(_) => unreachable!(),
+        }
+        assert!(matches!(map.entry(1), Entry::Vacant(_)));
+        assert_eq!(map.len(), 5);
+        assert!(map.keys().eq(&[3, 4, 7, 8, 9]));
+        check(&map.root, None, None);
+    }
+
+    #[test]
+    fn pop_first_last() {
+        let pairs = random_vec(1000, 50).into_iter().zip(0..);
+        let mut map: BstMap<u64, usize> = pairs.clone().collect();
+        let mut expected: BTreeMap<u64, usize> = pairs.collect();
+        for side in random_vec(expected.len() + 1, 51) {
+            if side % 2 == 0 {
+                assert_eq!(map.pop_first(), expected.pop_first());
+            } else {
+                assert_eq!(map.pop_last(), expected.pop_last());
+            }
+            assert_eq!(map.len(), expected.len());
+            check(&map.root, None, None);
+        }
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn clone_and_eq() {
+        let map = prepare_map();
+        let mut cloned = map.clone();
+        check(&cloned.root, None, None);
+        assert_eq!(map, cloned);
+
+        cloned.insert(4, String::from("four"));
+        assert_ne!(map, cloned);
+        cloned.insert(4, String::from("4"));
+        assert_eq!(map, cloned);
+        cloned.remove(&9);
+        assert_ne!(map, cloned);
+        assert_eq!(BstMap::<i32, i32>::new(), BstMap::new());
+    }
+
+    #[test]
+    fn random() {
+        let mut map = BstMap::new();
+        let mut expected = BTreeMap::new();
+        let ops = random_vec(3000, 45);
+        for (idx, key) in random_vec(3000, 46).into_iter().enumerate() {
+            let key = key % 200;
+            match ops[idx] % 4 {
+                0 => assert_eq!(map.remove(&key), expected.remove(&key)),
+                1 => *map.entry(key).or_insert(0) += 1,
+                _ => assert_eq!(map.insert(key, idx), expected.insert(key, idx)),
+            }
+            if ops[idx] % 4 == 1 {
+                *expected.entry(key).or_insert(0) += 1;
+            }
+            assert_eq!(map.len(), expected.len());
+        }
+        check(&map.root, None, None);
+        assert!(map.iter().eq(expected.iter()));
+        assert!(map.iter().rev().eq(expected.iter().rev()));
+
+        for (lo, hi) in random_vec(100, 47).into_iter().zip(random_vec(100, 48)) {
+            let (lo, hi) = (lo % 200, hi % 200);
+            let (lo, hi) = (lo.min(hi), lo.max(hi));
+            assert!(map.range(lo..hi).eq(expected.range(lo..hi)));
+            assert!(map.range(lo..=hi).rev().eq(expected.range(lo..=hi).rev()));
+
+            let sides = random_vec(hi as usize - lo as usize + 1, lo);
+            let mut iter = map.range_mut(lo..=hi);
+            let mut expected_iter = expected.range_mut(lo..=hi);
+            for side in sides {
+                if side % 2 == 0 {
+                    assert_eq!(iter.next(), expected_iter.next());
+                } else {
+                    assert_eq!(iter.next_back(), expected_iter.next_back());
+                }
+            }
+        }
+
+        let mut iter = map.into_iter();
+        let mut expected_iter = expected.into_iter();
+        for side in random_vec(iter.len() + 1, 49) {
+            if side % 2 == 0 {
+                assert_eq!(iter.next(), expected_iter.next());
+            } else {
+                assert_eq!(iter.next_back(), expected_iter.next_back());
+            }
+        }
+    }
+
+    /// 按从小到大的顺序插入 0..n 得到的退化树，逐个插入需要 O(n^2) 的时间，这里直接自底向上构建
+    fn sorted_chain(n: usize) -> BstMap<usize, usize> {
+        let mut link = None;
+        for key in (0..n).rev() {
+            let mut node = Node::new(Pair { key, val: key });
+            node.left = link;
+            node.size = n - key;
+            link = Some(Box::new(node));
+        }
+        BstMap { root: link }
+    }
+
+    #[test]
+    fn deep_tree() {
+        let mut map = BstMap::new();
+        for key in 0..1000 {
+            map.insert(key, key);
+        }
+        assert!(map.iter().eq(sorted_chain(1000).iter()));
+        check(&sorted_chain(1000).root, None, None);
+
+        let n = 1_000_000;
+        let mut map = sorted_chain(n);
+        assert_eq!(map.get(&(n - 1)), Some(&(n - 1)));
+        assert_eq!(map.range(n - 3..).count(), 3);
+        let mut iter = map.iter_mut();
+        assert_eq!(iter.next_back().map(|it| *it.0), Some(n - 1));
+        // 剩余的节点都在 back 栈中，之后每次 next 都从栈底取出节点
+        assert!(iter.map(|it| *it.0).eq(0..n - 1));
+        assert_eq!(map.remove(&(n - 2)), Some(n - 2));
+        assert_eq!(map.pop_last(), Some((n - 1, n - 1)));
+        assert_eq!(map.pop_first(), Some((0, 0)));
+        assert_eq!(map.len(), n - 3);
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(sorted_chain(n).clone(), sorted_chain(n));
+        drop(sorted_chain(n));
+
+        let mut iter = sorted_chain(n).into_iter();
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.next_back(), Some((n - 1, n - 1)));
+    }
+}
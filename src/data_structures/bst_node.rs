@@ -0,0 +1,516 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// 节点中保存的数据，BinarySearchTree 的节点保存一组相等的元素，BstMap 的节点保存一个键值对
+pub(crate) trait Item {
+    /// 节点中元素的数量
+    fn multiplicity(&self) -> usize;
+}
+
+/// BinarySearchTree 与 BstMap 共用的节点：左子树中的元素大于节点，右子树中的元素小于节点
+///
+/// 这里的操作都不使用递归，退化成链的树也不会导致栈溢出。
+pub(crate) struct Node<E> {
+    pub(crate) item: E,
+    pub(crate) left: Link<E>,
+    pub(crate) right: Link<E>,
+    /// 以该节点为根的子树中的元素数量
+    pub(crate) size: usize,
+}
+
+pub(crate) type Link<E> = Option<Box<Node<E>>>;
+
+impl<E> Node<E>
+where
+    E: Item,
+{
+    pub(crate) fn new(item: E) -> Self {
+        Self {
+            size: item.multiplicity(),
+            item,
+            left: None,
+            right: None,
+        }
+    }
+
+    pub(crate) fn multiplicity(&self) -> usize {
+        self.item.multiplicity()
+    }
+
+    /// 根据子节点重新计算子树中的元素数量
+    fn update_size(&mut self) {
+        self.size = size(&self.left) + size(&self.right) + self.multiplicity();
+    }
+}
+
+pub(crate) fn size<E>(link: &Link<E>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+/// 统计子树中满足条件的元素数量，条件对较小的元素成立、对较大的元素不成立
+pub(crate) fn count_below<E, F>(link: &Link<E>, is_below: F) -> usize
+where
+    E: Item,
+    F: Fn(&E) -> bool,
+{
+    let mut count = 0;
+    let mut link = link;
+    while let Some(node) = link {
+        if is_below(&node.item) {
+            // 右子树的元素都不大于当前节点
+            count += size(&node.right) + node.multiplicity();
+            link = &node.left;
+        } else {
+            link = &node.right;
+        }
+    }
+    count
+}
+
+/// 从子树中摘下与目标相等的节点（包括其中的所有元素），返回被摘下的节点
+///
+/// compare 返回节点与目标比较的结果，无法比较时视为不存在。
+/// 先确认存在相等的节点，再沿查找路径一次性减去该节点中元素的数量。
+pub(crate) fn take<E, F>(link: &mut Link<E>, mut compare: F) -> Option<Box<Node<E>>>
+where
+    E: Item,
+    F: FnMut(&E) -> Option<Ordering>,
+{
+    let mut target = link.as_deref();
+    let multiplicity = loop {
+        let node = target?;
+        target = match compare(&node.item)? {
+            // 节点小于目标
+            Ordering::Less => node.left.as_deref(),
+            // 节点大于目标
+            Ordering::Greater => node.right.as_deref(),
+            Ordering::Equal => break node.multiplicity(),
+        };
+    };
+
+    let mut link = link;
+    loop {
+        let ordering = compare(&link.as_ref()?.item)?;
+        if ordering == Ordering::Equal {
+            return delete(link);
+        }
+        let node = link.as_mut()?;
+        node.size -= multiplicity;
+        link = if ordering == Ordering::Less {
+            &mut node.left
+        } else {
+            &mut node.right
+        };
+    }
+}
+
+/// 删除子树的根节点，返回被删除的节点
+///
+/// 使用 Hibbard 删除：根节点有两个子节点时，用后继节点（左子树中的最小节点）替换根节点。
+pub(crate) fn delete<E>(link: &mut Link<E>) -> Option<Box<Node<E>>>
+where
+    E: Item,
+{
+    let mut node = link.take()?;
+    match (node.left.take(), node.right.take()) {
+        (None, child) | (child, None) => *link = child,
+        (mut left, right) => {
+            let mut successor = take_min(&mut left).unwrap();
+            successor.size = node.size - node.multiplicity();
+            successor.left = left;
+            successor.right = right;
+            *link = Some(successor);
+        }
+    }
+    Some(node)
+}
+
+/// 从子树中摘下最小节点
+pub(crate) fn take_min<E>(link: &mut Link<E>) -> Option<Box<Node<E>>>
+where
+    E: Item,
+{
+    let mut min = link.as_deref()?;
+    while let Some(right_node) = &min.right {
+        min = right_node;
+    }
+    let multiplicity = min.multiplicity();
+
+    let mut link = link;
+    while link.as_ref().is_some_and(|node| node.right.is_some()) {
+        let node = link.as_mut()?;
+        node.size -= multiplicity;
+        link = &mut node.right;
+    }
+    delete(link)
+}
+
+/// 从子树中摘下最大节点
+pub(crate) fn take_max<E>(link: &mut Link<E>) -> Option<Box<Node<E>>>
+where
+    E: Item,
+{
+    let mut max = link.as_deref()?;
+    while let Some(left_node) = &max.left {
+        max = left_node;
+    }
+    let multiplicity = max.multiplicity();
+
+    let mut link = link;
+    while link.as_ref().is_some_and(|node| node.left.is_some()) {
+        let node = link.as_mut()?;
+        node.size -= multiplicity;
+        link = &mut node.left;
+    }
+    delete(link)
+}
+
+/// 按从小到大的顺序拆下子树中的所有节点，拆下的节点不再有子节点
+pub(crate) fn into_nodes<E>(link: Link<E>) -> Vec<Box<Node<E>>> {
+    let mut nodes = Vec::with_capacity(size(&link));
+    let mut stack: Vec<Box<Node<E>>> = Vec::new();
+    let mut link = link;
+    loop {
+        while let Some(mut node) = link {
+            link = node.right.take();
+            stack.push(node);
+        }
+        let Some(mut node) = stack.pop() else {
+            return nodes;
+        };
+        link = node.left.take();
+        nodes.push(node);
+    }
+}
+
+/// 用按从小到大的顺序给出的 len 个节点构建平衡的子树，递归深度为 O(log n)
+pub(crate) fn build<E, I>(nodes: &mut I, len: usize) -> Link<E>
+where
+    E: Item,
+    I: Iterator<Item = Box<Node<E>>>,
+{
+    if len == 0 {
+        return None;
+    }
+    let smaller = len / 2;
+    let right = build(nodes, smaller);
+    let mut node = nodes.next()?;
+    node.left = build(nodes, len - smaller - 1);
+    node.right = right;
+    node.update_size();
+    Some(node)
+}
+
+/// 将根节点的较小子节点旋转为根节点
+fn rotate_up_right<E>(link: &mut Link<E>)
+where
+    E: Item,
+{
+    let Some(mut node) = link.take() else {
+        return;
+    };
+    let Some(mut child) = node.right.take() else {
+        *link = Some(node);
+        return;
+    };
+    node.right = child.left.take();
+    node.update_size();
+    child.left = Some(node);
+    child.update_size();
+    *link = Some(child);
+}
+
+/// 将根节点的较大子节点旋转为根节点
+fn rotate_up_left<E>(link: &mut Link<E>)
+where
+    E: Item,
+{
+    let Some(mut node) = link.take() else {
+        return;
+    };
+    let Some(mut child) = node.left.take() else {
+        *link = Some(node);
+        return;
+    };
+    node.left = child.right.take();
+    node.update_size();
+    child.right = Some(node);
+    child.update_size();
+    *link = Some(child);
+}
+
+/// 沿只有较大子节点的链，每隔一个节点旋转一次，共旋转 count 次
+fn compress<E>(link: &mut Link<E>, count: usize)
+where
+    E: Item,
+{
+    let mut link = link;
+    for _ in 0..count {
+        rotate_up_left(link);
+        match link {
+            None => return,
+            Some(node) => link = &mut node.left,
+        }
+    }
+}
+
+/// 用 Day–Stout–Warren 算法将子树调整为完全平衡的形状
+pub(crate) fn rebalance<E>(root: &mut Link<E>)
+where
+    E: Item,
+{
+    // 拉直成一条从小到大、只有较大子节点的链
+    let mut len = 0;
+    let mut link = &mut *root;
+    loop {
+        if link.as_ref().is_some_and(|node| node.right.is_some()) {
+            rotate_up_right(link);
+            continue;
+        }
+        match link {
+            None => break,
+            Some(node) => {
+                len += 1;
+                link = &mut node.left;
+            }
+        }
+    }
+
+    // 先压缩出最底层的节点，剩余的节点数为 2^k - 1，之后每次压缩一半
+    let mut full = 1;
+    while full * 2 <= len + 1 {
+        full *= 2;
+    }
+    compress(root, len + 1 - full);
+    let mut len = full - 1;
+    while len > 1 {
+        len /= 2;
+        compress(root, len);
+    }
+}
+
+/// 释放子树中的所有节点
+///
+/// 不断将左子节点右旋到根节点，根节点没有左子节点时释放根节点并继续处理右子树，
+/// 每个节点释放时都没有子节点，不会产生递归的析构。
+pub(crate) fn drop_link<E>(mut link: Link<E>) {
+    while let Some(mut node) = link {
+        link = match node.left.take() {
+            Some(mut left) => {
+                node.left = left.right.take();
+                left.right = Some(node);
+                Some(left)
+            }
+            None => node.right.take(),
+        };
+    }
+}
+
+/// 按中序遍历访问节点
+///
+/// 两端各使用一个栈，front 栈顶为下一个最小的节点，back 栈顶为下一个最大的节点。
+/// 两端不会自行停止，由外层的迭代器通过剩余元素数量判断两端是否相遇。
+pub(crate) struct Nodes<'a, E> {
+    front: Vec<&'a Node<E>>,
+    back: Vec<&'a Node<E>>,
+}
+
+impl<'a, E> Nodes<'a, E> {
+    pub(crate) fn new(link: &'a Link<E>) -> Self {
+        let mut nodes = Self {
+            front: Vec::new(),
+            back: Vec::new(),
+        };
+        nodes.push_front(link);
+        nodes.push_back(link);
+        nodes
+    }
+
+    /// 只访问范围内的节点：front 栈只压入不小于范围起点的节点，back 栈只压入不大于范围终点的节点
+    pub(crate) fn range<F, G>(link: &'a Link<E>, is_below: F, is_above: G) -> Self
+    where
+        F: Fn(&E) -> bool,
+        G: Fn(&E) -> bool,
+    {
+        let mut nodes = Self {
+            front: Vec::new(),
+            back: Vec::new(),
+        };
+        let mut next = link;
+        while let Some(node) = next {
+            if is_below(&node.item) {
+                next = &node.left;
+            } else {
+                nodes.front.push(node);
+                next = &node.right;
+            }
+        }
+        let mut next = link;
+        while let Some(node) = next {
+            if is_above(&node.item) {
+                next = &node.right;
+            } else {
+                nodes.back.push(node);
+                next = &node.left;
+            }
+        }
+        nodes
+    }
+
+    /// 沿较小的子节点一路向下，将经过的节点压入 front 栈
+    fn push_front(&mut self, mut link: &'a Link<E>) {
+        while let Some(node) = link {
+            self.front.push(node);
+            link = &node.right;
+        }
+    }
+
+    /// 沿较大的子节点一路向下，将经过的节点压入 back 栈
+    fn push_back(&mut self, mut link: &'a Link<E>) {
+        while let Some(node) = link {
+            self.back.push(node);
+            link = &node.left;
+        }
+    }
+
+    pub(crate) fn next_front(&mut self) -> Option<&'a Node<E>> {
+        let node = self.front.pop()?;
+        self.push_front(&node.left);
+        Some(node)
+    }
+
+    pub(crate) fn next_back(&mut self) -> Option<&'a Node<E>> {
+        let node = self.back.pop()?;
+        self.push_back(&node.right);
+        Some(node)
+    }
+}
+
+/// 按中序遍历取出节点，取出的节点不再有子节点
+///
+/// front 栈中节点的较小子树已被取出，节点依次从栈顶按从小到大的顺序取出，每个节点取出后再处理其较大的子树；
+/// back 栈与之对称。一端的栈为空时，从另一端栈底的节点中拆出剩余的子树。
+/// 两个栈都用 VecDeque 保存，从栈底取出节点也只需要 O(1) 的时间。
+pub(crate) struct IntoNodes<E> {
+    front: VecDeque<Box<Node<E>>>,
+    back: VecDeque<Box<Node<E>>>,
+}
+
+impl<E> IntoNodes<E> {
+    pub(crate) fn new(link: Link<E>) -> Self {
+        let mut nodes = Self {
+            front: VecDeque::new(),
+            back: VecDeque::new(),
+        };
+        nodes.push_front(link);
+        nodes
+    }
+
+    fn push_front(&mut self, mut link: Link<E>) {
+        while let Some(mut node) = link {
+            link = node.right.take();
+            self.front.push_back(node);
+        }
+    }
+
+    fn push_back(&mut self, mut link: Link<E>) {
+        while let Some(mut node) = link {
+            link = node.left.take();
+            self.back.push_back(node);
+        }
+    }
+
+    pub(crate) fn next_front(&mut self) -> Option<Box<Node<E>>> {
+        if self.front.is_empty() {
+            // back 栈底节点的较小子树中是剩余的最小节点，没有较小子树时栈底节点即为最小节点
+            match self.back.front_mut()?.right.take() {
+                Some(node) => self.push_front(Some(node)),
+                None => return self.back.pop_front(),
+            }
+        }
+        let mut node = self.front.pop_back()?;
+        self.push_front(node.left.take());
+        Some(node)
+    }
+
+    pub(crate) fn next_back(&mut self) -> Option<Box<Node<E>>> {
+        if self.back.is_empty() {
+            match self.front.front_mut()?.left.take() {
+                Some(node) => self.push_back(Some(node)),
+                None => return self.front.pop_front(),
+            }
+        }
+        let mut node = self.back.pop_back()?;
+        self.push_back(node.right.take());
+        Some(node)
+    }
+}
+
+impl<E> Drop for IntoNodes<E> {
+    fn drop(&mut self) {
+        for node in self.front.drain(..) {
+            drop_link(Some(node));
+        }
+        for node in self.back.drain(..) {
+            drop_link(Some(node));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntoNodes, Item, Link, Node};
+    use alloc::boxed::Box;
+
+    impl Item for u64 {
+        fn multiplicity(&self) -> usize {
+            1
+        }
+    }
+
+    /// 按从小到大的顺序插入 0, 1, 2, ... 得到的退化树，每个节点只有较大的子节点
+    fn ascending_chain(n: u64) -> Link<u64> {
+        let mut link = None;
+        for val in (0..n).rev() {
+            let mut node = Node::new(val);
+            node.size = (n - val) as usize;
+            node.left = link;
+            link = Some(Box::new(node));
+        }
+        link
+    }
+
+    /// 按从大到小的顺序插入 n - 1, n - 2, ... 得到的退化树，每个节点只有较小的子节点
+    fn descending_chain(n: u64) -> Link<u64> {
+        let mut link = None;
+        for val in 0..n {
+            let mut node = Node::new(val);
+            node.size = val as usize + 1;
+            node.right = link;
+            link = Some(Box::new(node));
+        }
+        link
+    }
+
+    #[test]
+    fn test_into_nodes_long_chain() {
+        let n = 200_000;
+
+        // 所有节点都在 front 栈中，每次 next_back 都从栈底取出节点
+        let mut nodes = IntoNodes::new(descending_chain(n));
+        for val in (0..n).rev() {
+            assert_eq!(nodes.next_back().map(|node| node.item), Some(val));
+        }
+        assert!(nodes.next_back().is_none());
+        assert!(nodes.next_front().is_none());
+
+        let mut nodes = IntoNodes::new(ascending_chain(n));
+        assert_eq!(nodes.next_back().map(|node| node.item), Some(n - 1));
+        for val in 0..n - 1 {
+            assert_eq!(nodes.next_front().map(|node| node.item), Some(val));
+        }
+        assert!(nodes.next_front().is_none());
+    }
+}
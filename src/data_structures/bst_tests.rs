@@ -0,0 +1,118 @@
+/// 生成 BinarySearchTree 与 ScapegoatTree 共用的测试
+///
+/// 调用处需要提供 `sorted_values` 函数：检查树的结构，并按从小到大的顺序返回所有元素。
+/// 生成的 `prepare_tree` 也可以在调用处的其它测试中使用。
+macro_rules! bst_tests {
+    ($tree:ident) => {
+        fn prepare_tree() -> $tree<i32> {
+            let mut result = $tree::new();
+            result.insert(1);
+            result.insert(2);
+            result.insert(3);
+            result.insert(5);
+            result.insert(6);
+            result.insert(7);
+            result
+        }
+
+        #[test]
+        fn test_search() {
+            let tree = prepare_tree();
+            assert!(tree.search(&1));
+            assert!(tree.search(&2));
+            assert!(tree.search(&3));
+
+            assert!(!tree.search(&4));
+        }
+
+        #[test]
+        fn test_max_and_min() {
+            let tree = prepare_tree();
+            assert_eq!(tree.max(), Some(&7));
+            assert_eq!(tree.min(), Some(&1));
+        }
+
+        #[test]
+        fn test_floor_and_ceil() {
+            let tree = prepare_tree();
+            assert_eq!(tree.floor(&4), Some(&3));
+            assert_eq!(tree.ceil(&4), Some(&5));
+        }
+
+        #[test]
+        fn test_remove() {
+            let mut tree = prepare_tree();
+            assert_eq!(tree.remove(&4), None);
+            assert_eq!(tree.remove(&3), Some(3));
+            assert!(!tree.search(&3));
+            assert_eq!(sorted_values(&tree), [1, 2, 5, 6, 7]);
+        }
+
+        #[test]
+        fn test_pop_min_and_max() {
+            let mut tree = prepare_tree();
+            assert_eq!(tree.pop_min(), Some(1));
+            assert_eq!(tree.pop_max(), Some(7));
+            assert_eq!(tree.pop_min(), Some(2));
+            assert_eq!(sorted_values(&tree), [3, 5, 6]);
+
+            let mut tree: $tree<i32> = $tree::new();
+            assert_eq!(tree.pop_min(), None);
+            assert_eq!(tree.pop_max(), None);
+        }
+
+        #[test]
+        fn test_retain() {
+            let mut tree = prepare_tree();
+            let mut visited = alloc::vec::Vec::new();
+            tree.retain(|val| {
+                visited.push(*val);
+                val % 2 == 1
+            });
+            assert_eq!(visited, [1, 2, 3, 5, 6, 7]);
+            assert_eq!(sorted_values(&tree), [1, 3, 5, 7]);
+        }
+
+        #[test]
+        fn test_random_insert_and_remove() {
+            let mut tree = $tree::new();
+            let mut expected = alloc::vec::Vec::new();
+            let ops = $crate::sort::random_vec(2000, 36);
+            for (idx, val) in $crate::sort::random_vec(2000, 37).into_iter().enumerate() {
+                let val = val % 100;
+                if ops[idx].is_multiple_of(3) {
+                    let pos = expected.iter().position(|it| *it == val);
+                    assert_eq!(tree.remove(&val), pos.map(|pos| expected.remove(pos)));
+                } else {
+                    tree.insert(val);
+                    expected.push(val);
+                }
+
+                expected.sort();
+                assert_eq!(sorted_values(&tree), expected);
+            }
+
+            while let Some(min) = tree.pop_min() {
+                assert_eq!(min, expected.remove(0));
+                assert_eq!(sorted_values(&tree), expected);
+            }
+        }
+
+        #[test]
+        fn test_len() {
+            let mut tree = prepare_tree();
+            assert_eq!(tree.len(), 6);
+            tree.insert(3);
+            assert_eq!(tree.len(), 7);
+            tree.remove(&3);
+            tree.remove(&4);
+            tree.pop_max();
+            assert_eq!(tree.len(), 5);
+            tree.retain(|val| *val > 2);
+            assert_eq!(tree.len(), 3);
+            assert!(!tree.is_empty());
+        }
+    };
+}
+
+pub(crate) use bst_tests;
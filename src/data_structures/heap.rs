@@ -1,5 +1,7 @@
-use std::cmp::Ordering;
-use std::cmp::Ordering::Less;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::cmp::Ordering::Less;
 
 /// 堆结构
 pub struct Heap<T>
@@ -16,9 +18,9 @@ where
     T: Default,
 {
     /// 创建一个空堆
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Heap;
     /// let heap:Heap<i32> = Heap::new(|i1, i2| i1.partial_cmp(i2));
@@ -32,13 +34,13 @@ where
     }
 
     /// 返回堆的元素数量
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Heap;
     /// let heap:Heap<i32> = Heap::new(|i1, i2| i1.partial_cmp(i2));
-    /// 
+    ///
     /// assert_eq!(heap.len(), 0);
     /// ```
     pub fn len(&self) -> usize {
@@ -46,13 +48,13 @@ where
     }
 
     /// 返回堆是否为空
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Heap;
     /// let heap:Heap<i32> = Heap::new(|i1, i2| i1.partial_cmp(i2));
-    /// 
+    ///
     /// assert!(heap.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
@@ -60,13 +62,13 @@ where
     }
 
     /// 向堆中添加一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Heap;
     /// let mut heap:Heap<i32> = Heap::new(|i1, i2| i1.partial_cmp(i2));
-    /// 
+    ///
     /// heap.push(1);
     /// ```
     pub fn push(&mut self, item: T) {
@@ -84,13 +86,13 @@ where
     }
 
     /// 从堆中去除一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Heap;
     /// let mut heap:Heap<i32> = Heap::new(|i1, i2| i1.partial_cmp(i2));
-    /// 
+    ///
     /// assert_eq!(heap.pop(), None);
     /// ```
     pub fn pop(&mut self) -> Option<T> {
@@ -150,9 +152,9 @@ where
     T: Default + Ord,
 {
     /// 创建一个空元素的小顶堆
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Heap;
     /// let mut heap:Heap<i32> = Heap::new_min();
@@ -162,9 +164,9 @@ where
     }
 
     /// 创建一个空元素的大顶堆
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Heap;
     /// let mut heap:Heap<i32> = Heap::new_max();
@@ -223,13 +225,8 @@ mod tests {
         assert_eq!(heap.pop(), Some(2));
     }
 
-    struct Point(i32, i32);
-
-    impl Default for Point {
-        fn default() -> Self {
-            Point(0, 0)
-        }
-    }
+    #[derive(Default)]
+    struct Point(i32, #[allow(dead_code)] i32);
 
     #[test]
     fn custom_comparator_heap() {
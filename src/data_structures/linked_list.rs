@@ -1,4 +1,5 @@
-use std::ptr::NonNull;
+use alloc::boxed::Box;
+use core::ptr::NonNull;
 
 struct Node<T> {
     val: T,
@@ -19,7 +20,7 @@ impl<T> Node<T> {
 type Link<T> = Option<NonNull<Node<T>>>;
 
 /// 双向链表
-/// 
+///
 /// 可在头部和尾部插入和删除元素，且时间复杂度都为 O(1)；
 /// 但如果通过索引进行添加和删除元素时，时间复杂度为 O(n)。
 pub struct LinkedList<T> {
@@ -30,9 +31,9 @@ pub struct LinkedList<T> {
 
 impl<T> LinkedList<T> {
     /// 创建一个空元素的双向链表
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let list:LinkedList<i32> = LinkedList::new();
@@ -46,13 +47,13 @@ impl<T> LinkedList<T> {
     }
 
     /// 返回链表的元素数量
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let list:LinkedList<i32> = LinkedList::new();
-    /// 
+    ///
     /// assert_eq!(list.size(), 0);
     /// ```
     pub fn size(&self) -> usize {
@@ -60,9 +61,9 @@ impl<T> LinkedList<T> {
     }
 
     /// 从链表头部添加一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let mut list = LinkedList::new();
@@ -81,9 +82,9 @@ impl<T> LinkedList<T> {
     }
 
     /// 从链表尾部添加一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let mut list = LinkedList::new();
@@ -102,13 +103,13 @@ impl<T> LinkedList<T> {
     }
 
     /// 从链表头部添加一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let mut list:LinkedList<i32> = LinkedList::new();
-    /// 
+    ///
     /// assert_eq!(list.poll_head(), None);
     /// ```
     pub fn poll_head(&mut self) -> Option<T> {
@@ -125,13 +126,13 @@ impl<T> LinkedList<T> {
     }
 
     /// 从链表尾部添加一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let mut list:LinkedList<i32> = LinkedList::new();
-    /// 
+    ///
     /// assert_eq!(list.poll_tail(), None);
     /// ```
     pub fn poll_tail(&mut self) -> Option<T> {
@@ -148,13 +149,13 @@ impl<T> LinkedList<T> {
     }
 
     /// 在链表指定索引位置添加元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let mut list = LinkedList::new();
-    /// 
+    ///
     /// list.insert(0, 1);
     /// ```
     pub fn insert(&mut self, index: usize, val: T) {
@@ -197,13 +198,13 @@ impl<T> LinkedList<T> {
     }
 
     /// 从链表指定索引位置删除元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let mut list:LinkedList<i32> = LinkedList::new();
-    /// 
+    ///
     /// list.offer_head(1);
     /// assert_eq!(list.remove(0), Some(1));
     /// ```
@@ -242,13 +243,13 @@ impl<T> LinkedList<T> {
     }
 
     /// 获取链表指定索引位置元素引用
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::LinkedList;
     /// let mut list:LinkedList<i32> = LinkedList::new();
-    /// 
+    ///
     /// list.offer_head(1);
     /// assert_eq!(list.get(0), Some(&1));
     /// ```
@@ -275,6 +276,12 @@ impl<T> LinkedList<T> {
     }
 }
 
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
         while self.poll_head().is_some() {}
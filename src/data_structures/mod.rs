@@ -1,11 +1,30 @@
-pub use self::binary_search_tree::BinarySearchTree;
+pub use self::avl_tree::AvlTree;
+pub use self::binary_search_tree::{BinarySearchTree, Compare, DuplicatePolicy, NaturalOrder};
+pub use self::bst_map::{BstMap, Entry, OccupiedEntry, VacantEntry};
 pub use self::heap::Heap;
 pub use self::linked_list::LinkedList;
+pub use self::persistent_set::{
+    ArcPointer, PersistentSet, RcPointer, SharedPointer, SyncPersistentSet,
+};
+pub use self::red_black_tree::RedBlackTree;
+pub use self::scapegoat_tree::ScapegoatTree;
+pub use self::splay_tree::SplayTree;
 pub use self::stack::Stack;
+pub use self::treap::{ImplicitTreap, PriorityGenerator, Treap, XorShift};
 pub use self::union_find::UnionFind;
 
+mod avl_tree;
 mod binary_search_tree;
+mod bst_map;
+mod bst_node;
+#[cfg(test)]
+mod bst_tests;
 mod heap;
 mod linked_list;
+mod persistent_set;
+mod red_black_tree;
+mod scapegoat_tree;
+mod splay_tree;
 mod stack;
+mod treap;
 mod union_find;
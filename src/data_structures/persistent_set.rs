@@ -0,0 +1,700 @@
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug};
+use core::iter::FusedIterator;
+use core::ops::Deref;
+
+/// 持久化集合中节点使用的共享指针类型
+///
+/// 由 [`RcPointer`] 和 [`ArcPointer`] 实现，分别对应单线程和可跨线程共享的版本。
+pub trait SharedPointer {
+    type Pointer<N>: Clone + Deref<Target = N>;
+
+    /// 创建一个指向 node 的共享指针
+    fn new<N>(node: N) -> Self::Pointer<N>;
+}
+
+/// 使用 [`Rc`] 共享节点
+#[derive(Debug)]
+pub enum RcPointer {}
+
+impl SharedPointer for RcPointer {
+    type Pointer<N> = Rc<N>;
+
+    fn new<N>(node: N) -> Rc<N> {
+        Rc::new(node)
+    }
+}
+
+/// 使用 [`Arc`] 共享节点，集合的各个版本可以在多个线程中同时读取
+#[derive(Debug)]
+pub enum ArcPointer {}
+
+impl SharedPointer for ArcPointer {
+    type Pointer<N> = Arc<N>;
+
+    fn new<N>(node: N) -> Arc<N> {
+        Arc::new(node)
+    }
+}
+
+struct Node<T, P>
+where
+    P: SharedPointer,
+{
+    val: T,
+    left: Link<T, P>,
+    right: Link<T, P>,
+    height: usize,
+}
+
+type Link<T, P> = Option<<P as SharedPointer>::Pointer<Node<T, P>>>;
+
+fn height<T, P>(link: &Link<T, P>) -> usize
+where
+    P: SharedPointer,
+{
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+/// 创建一个新节点，新节点与原有版本共享 left 和 right 子树
+fn node<T, P>(left: Link<T, P>, val: T, right: Link<T, P>) -> Link<T, P>
+where
+    P: SharedPointer,
+{
+    let height = height::<T, P>(&left).max(height::<T, P>(&right)) + 1;
+    Some(P::new(Node {
+        val,
+        left,
+        right,
+        height,
+    }))
+}
+
+/// 创建一个新节点，左右子树的高度差不超过 2 时通过旋转使其重新满足 AVL 树的性质
+///
+/// 旋转时不修改原有节点，而是复制旋转涉及的节点。
+fn balance<T, P>(left: Link<T, P>, val: T, right: Link<T, P>) -> Link<T, P>
+where
+    T: Clone,
+    P: SharedPointer,
+{
+    let (left_height, right_height) = (height::<T, P>(&left), height::<T, P>(&right));
+    if left_height > right_height + 1 {
+        let l = left.as_deref().unwrap();
+        if height::<T, P>(&l.left) >= height::<T, P>(&l.right) {
+            return node::<T, P>(
+                l.left.clone(),
+                l.val.clone(),
+                node::<T, P>(l.right.clone(), val, right),
+            );
+        }
+        let lr = l.right.as_deref().unwrap();
+        return node::<T, P>(
+            node::<T, P>(l.left.clone(), l.val.clone(), lr.left.clone()),
+            lr.val.clone(),
+            node::<T, P>(lr.right.clone(), val, right),
+        );
+    }
+    if right_height > left_height + 1 {
+        let r = right.as_deref().unwrap();
+        if height::<T, P>(&r.right) >= height::<T, P>(&r.left) {
+            return node::<T, P>(
+                node::<T, P>(left, val, r.left.clone()),
+                r.val.clone(),
+                r.right.clone(),
+            );
+        }
+        let rl = r.left.as_deref().unwrap();
+        return node::<T, P>(
+            node::<T, P>(left, val, rl.left.clone()),
+            rl.val.clone(),
+            node::<T, P>(rl.right.clone(), r.val.clone(), r.right.clone()),
+        );
+    }
+    node::<T, P>(left, val, right)
+}
+
+/// 返回插入后的新子树，值已存在时返回 None
+fn insert<T, P>(link: &Link<T, P>, val: T) -> Option<Link<T, P>>
+where
+    T: Ord + Clone,
+    P: SharedPointer,
+{
+    let Some(current) = link else {
+        return Some(node::<T, P>(None, val, None));
+    };
+
+    match val.cmp(&current.val) {
+        Ordering::Less => {
+            let left = insert::<T, P>(&current.left, val)?;
+            Some(balance::<T, P>(
+                left,
+                current.val.clone(),
+                current.right.clone(),
+            ))
+        }
+        Ordering::Greater => {
+            let right = insert::<T, P>(&current.right, val)?;
+            Some(balance::<T, P>(
+                current.left.clone(),
+                current.val.clone(),
+                right,
+            ))
+        }
+        Ordering::Equal => None,
+    }
+}
+
+/// 返回删除后的新子树，值不存在时返回 None
+fn remove<T, P>(link: &Link<T, P>, val: &T) -> Option<Link<T, P>>
+where
+    T: Ord + Clone,
+    P: SharedPointer,
+{
+    let current = link.as_deref()?;
+    match val.cmp(&current.val) {
+        Ordering::Less => {
+            let left = remove::<T, P>(&current.left, val)?;
+            Some(balance::<T, P>(
+                left,
+                current.val.clone(),
+                current.right.clone(),
+            ))
+        }
+        Ordering::Greater => {
+            let right = remove::<T, P>(&current.right, val)?;
+            Some(balance::<T, P>(
+                current.left.clone(),
+                current.val.clone(),
+                right,
+            ))
+        }
+        Ordering::Equal => match (&current.left, &current.right) {
+            (None, child) | (child, None) => Some(child.clone()),
+            // 用右子树的最小值替换被删除的节点
+            (left, Some(right)) => {
+                let (min, right) = remove_min(right);
+                Some(balance::<T, P>(left.clone(), min, right))
+            }
+        },
+    }
+}
+
+/// 返回子树的最小值以及删除最小值后的新子树
+fn remove_min<T, P>(node: &Node<T, P>) -> (T, Link<T, P>)
+where
+    T: Clone,
+    P: SharedPointer,
+{
+    match &node.left {
+        None => (node.val.clone(), node.right.clone()),
+        Some(left) => {
+            let (min, left) = remove_min(left);
+            (
+                min,
+                balance::<T, P>(left, node.val.clone(), node.right.clone()),
+            )
+        }
+    }
+}
+
+/// 持久化有序集合
+///
+/// 插入和删除不修改原集合，而是返回一个新版本。新版本只复制从根节点到被修改位置路径上的节点，
+/// 其余子树与旧版本共享，单次修改的时间和额外空间均为 O(logn)，克隆一个版本的时间为 O(1)。
+/// 内部以 AVL 树保持平衡，复制路径上的节点时会克隆其中的值，元素类型的克隆开销较大时可以将其放入 [`Rc`] 中。
+///
+/// 节点默认使用 [`Rc`] 共享，使用 [`SyncPersistentSet`] 可以在多个线程间共享各个版本。
+pub struct PersistentSet<T, P = RcPointer>
+where
+    P: SharedPointer,
+{
+    root: Link<T, P>,
+    len: usize,
+}
+
+/// 使用 [`Arc`] 共享节点的持久化有序集合，可以在多个线程中同时读取同一个版本
+pub type SyncPersistentSet<T> = PersistentSet<T, ArcPointer>;
+
+impl<T, P> PersistentSet<T, P>
+where
+    T: Ord + Clone,
+    P: SharedPointer,
+{
+    /// 创建一个空集合
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = PersistentSet::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// 获取元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = PersistentSet::new();
+    ///
+    /// assert_eq!(set.insert(1).len(), 1);
+    /// assert_eq!(set.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 判断集合是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = PersistentSet::new();
+    ///
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 返回插入指定值后的新版本，原集合不变；值已存在时返回与原集合共享全部节点的版本
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let v0: PersistentSet<i32> = PersistentSet::new();
+    /// let v1 = v0.insert(1);
+    /// let v2 = v1.insert(2);
+    ///
+    /// assert!(v0.is_empty());
+    /// assert!(v1.iter().eq(&[1]));
+    /// assert!(v2.iter().eq(&[1, 2]));
+    /// ```
+    pub fn insert(&self, val: T) -> Self {
+        match insert::<T, P>(&self.root, val) {
+            Some(root) => Self {
+                root,
+                len: self.len + 1,
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// 返回删除指定值后的新版本，原集合不变；值不存在时返回与原集合共享全部节点的版本
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let v0: PersistentSet<i32> = [1, 2].into_iter().collect();
+    /// let v1 = v0.remove(&1);
+    ///
+    /// assert!(v0.iter().eq(&[1, 2]));
+    /// assert!(v1.iter().eq(&[2]));
+    /// ```
+    pub fn remove(&self, val: &T) -> Self {
+        match remove::<T, P>(&self.root, val) {
+            Some(root) => Self {
+                root,
+                len: self.len - 1,
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// 查询集合中是否存在指定值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = [1].into_iter().collect();
+    ///
+    /// assert!(set.contains(&1));
+    /// assert!(!set.contains(&2));
+    /// ```
+    pub fn contains(&self, val: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match val.cmp(&node.val) {
+                Ordering::Less => link = &node.left,
+                Ordering::Greater => link = &node.right,
+                Ordering::Equal => return true,
+            }
+        }
+        false
+    }
+
+    /// 获取最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = [1, 2].into_iter().collect();
+    ///
+    /// assert_eq!(set.max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = [1, 2].into_iter().collect();
+    ///
+    /// assert_eq!(set.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取不大于指定值的最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = [1, 3].into_iter().collect();
+    ///
+    /// assert_eq!(set.floor(&2), Some(&1));
+    /// assert_eq!(set.floor(&0), None);
+    /// ```
+    pub fn floor(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.cmp(val) {
+                Ordering::Greater => link = &node.left,
+                Ordering::Less => {
+                    result = Some(&node.val);
+                    link = &node.right;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取不小于指定值的最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = [1, 3].into_iter().collect();
+    ///
+    /// assert_eq!(set.ceil(&2), Some(&3));
+    /// assert_eq!(set.ceil(&4), None);
+    /// ```
+    pub fn ceil(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.cmp(val) {
+                Ordering::Less => link = &node.right,
+                Ordering::Greater => {
+                    result = Some(&node.val);
+                    link = &node.left;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取按从小到大的顺序迭代元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::PersistentSet;
+    /// let set: PersistentSet<i32> = [2, 3, 1].into_iter().collect();
+    ///
+    /// assert!(set.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            len: self.len,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T, P> Clone for PersistentSet<T, P>
+where
+    P: SharedPointer,
+{
+    /// 克隆只复制根节点的指针，时间复杂度为 O(1)
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T, P> Default for PersistentSet<T, P>
+where
+    T: Ord + Clone,
+    P: SharedPointer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P> Debug for PersistentSet<T, P>
+where
+    T: Ord + Clone + Debug,
+    P: SharedPointer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T, P> FromIterator<T> for PersistentSet<T, P>
+where
+    T: Ord + Clone,
+    P: SharedPointer,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter()
+            .fold(Self::new(), |set, val| set.insert(val))
+    }
+}
+
+impl<'a, T, P> IntoIterator for &'a PersistentSet<T, P>
+where
+    T: Ord + Clone,
+    P: SharedPointer,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, P>;
+
+    fn into_iter(self) -> Iter<'a, T, P> {
+        self.iter()
+    }
+}
+
+/// 中序遍历的迭代器
+pub struct Iter<'a, T, P>
+where
+    P: SharedPointer,
+{
+    stack: Vec<&'a Node<T, P>>,
+    len: usize,
+}
+
+impl<'a, T, P> Iter<'a, T, P>
+where
+    P: SharedPointer,
+{
+    fn push_left(&mut self, mut link: &'a Link<T, P>) {
+        while let Some(node) = link.as_deref() {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T, P> Iterator for Iter<'a, T, P>
+where
+    P: SharedPointer,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.len -= 1;
+        Some(&node.val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, P> ExactSizeIterator for Iter<'_, T, P> where P: SharedPointer {}
+
+impl<T, P> FusedIterator for Iter<'_, T, P> where P: SharedPointer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::random_vec;
+    use alloc::collections::BTreeSet;
+    use alloc::format;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// 检查 AVL 树的性质，返回子树的高度
+    fn check_node<T, P>(link: &Link<T, P>) -> usize
+    where
+        T: Ord,
+        P: SharedPointer,
+    {
+        let Some(node) = link.as_deref() else {
+            return 0;
+        };
+        assert!(node.left.as_deref().is_none_or(|left| left.val < node.val));
+        assert!(node
+            .right
+            .as_deref()
+            .is_none_or(|right| right.val > node.val));
+        let (left, right) = (
+            check_node::<T, P>(&node.left),
+            check_node::<T, P>(&node.right),
+        );
+        assert!(left.abs_diff(right) <= 1);
+        assert_eq!(node.height, left.max(right) + 1);
+        node.height
+    }
+
+    fn check<T, P>(set: &PersistentSet<T, P>)
+    where
+        T: Ord,
+        P: SharedPointer,
+    {
+        assert_eq!(check_node::<T, P>(&set.root), height::<T, P>(&set.root));
+    }
+
+    /// 统计两个版本共享的节点数量
+    fn shared<T, P>(a: &PersistentSet<T, P>, b: &PersistentSet<T, P>) -> usize
+    where
+        P: SharedPointer,
+    {
+        let mut nodes = Vec::new();
+        let mut stack: Vec<&Node<T, P>> = a.root.as_deref().into_iter().collect();
+        while let Some(node) = stack.pop() {
+            nodes.push(node as *const Node<T, P>);
+            stack.extend(node.left.as_deref());
+            stack.extend(node.right.as_deref());
+        }
+
+        let mut result = 0;
+        let mut stack: Vec<&Node<T, P>> = b.root.as_deref().into_iter().collect();
+        while let Some(node) = stack.pop() {
+            if nodes.contains(&(node as *const Node<T, P>)) {
+                result += 1;
+            }
+            stack.extend(node.left.as_deref());
+            stack.extend(node.right.as_deref());
+        }
+        result
+    }
+
+    #[test]
+    fn queries() {
+        let set: PersistentSet<i32> = [1, 2, 3, 5, 6, 7].into_iter().collect();
+        check(&set);
+        assert!(set.contains(&1));
+        assert!(!set.contains(&4));
+        assert_eq!(set.max(), Some(&7));
+        assert_eq!(set.min(), Some(&1));
+        assert_eq!(set.floor(&4), Some(&3));
+        assert_eq!(set.ceil(&4), Some(&5));
+        assert_eq!(format!("{set:?}"), "{1, 2, 3, 5, 6, 7}");
+    }
+
+    #[test]
+    fn versions() {
+        let v0: PersistentSet<i32> = PersistentSet::new();
+        let v1 = v0.insert(2);
+        let v2 = v1.insert(1).insert(3);
+        let v3 = v2.remove(&2);
+        let v4 = v3.insert(3);
+
+        assert!(v0.is_empty());
+        assert!(v1.iter().eq(&[2]));
+        assert!(v2.iter().eq(&[1, 2, 3]));
+        assert!(v3.iter().eq(&[1, 3]));
+        assert_eq!(v4.len(), 2);
+        assert_eq!(shared(&v3, &v4), 2);
+        assert_eq!(v3.remove(&2).len(), 2);
+    }
+
+    #[test]
+    fn structural_sharing() {
+        let old: PersistentSet<u64> = (0..1000).collect();
+        let new = old.insert(1000);
+        check(&new);
+
+        // 只复制根节点到新节点路径上的节点
+        let height = height::<u64, RcPointer>(&new.root);
+        assert!(shared(&old, &new) >= 1000 - height);
+        assert!(old.iter().copied().eq(0..1000));
+        assert!(new.iter().copied().eq(0..1001));
+
+        let new = old.remove(&500);
+        check(&new);
+        assert!(shared(&old, &new) >= 999 - 2 * height);
+    }
+
+    #[test]
+    fn random() {
+        let mut versions = vec![(PersistentSet::<u64>::new(), BTreeSet::new())];
+        let ops = random_vec(2000, 62);
+        for (idx, val) in random_vec(2000, 63).into_iter().enumerate() {
+            let (set, expected) = &versions[ops[idx] as usize % versions.len()];
+            let (mut set, mut expected) = (set.clone(), expected.clone());
+            let val = val % 200;
+            if ops[idx].is_multiple_of(3) {
+                set = set.remove(&val);
+                expected.remove(&val);
+            } else {
+                set = set.insert(val);
+                expected.insert(val);
+            }
+            versions.push((set, expected));
+        }
+
+        for (set, expected) in &versions {
+            check(set);
+            assert_eq!(set.len(), expected.len());
+            assert!(set.iter().eq(expected.iter()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn threads() {
+        let v1: SyncPersistentSet<u64> = (0..1000).collect();
+        let v2 = v1.remove(&0);
+
+        let handles: Vec<_> = [v1, v2]
+            .into_iter()
+            .map(|set| std::thread::spawn(move || set.iter().sum::<u64>()))
+            .collect();
+        let sums: Vec<u64> = handles.into_iter().map(|it| it.join().unwrap()).collect();
+        assert_eq!(sums, [499500, 499500]);
+    }
+}
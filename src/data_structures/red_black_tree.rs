@@ -0,0 +1,730 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::mem;
+
+const RED: bool = true;
+const BLACK: bool = false;
+
+struct Node<T>
+where
+    T: PartialOrd,
+{
+    val: T,
+    left: Link<T>,
+    right: Link<T>,
+    /// 指向该节点的链接的颜色
+    color: bool,
+}
+
+impl<T> Node<T>
+where
+    T: PartialOrd,
+{
+    fn new(val: T) -> Self {
+        Self {
+            val,
+            left: None,
+            right: None,
+            color: RED,
+        }
+    }
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+fn is_red<T>(link: &Link<T>) -> bool
+where
+    T: PartialOrd,
+{
+    link.as_ref().is_some_and(|node| node.color == RED)
+}
+
+/// 左子节点的左子节点是否为红色
+fn is_left_left_red<T>(link: &Link<T>) -> bool
+where
+    T: PartialOrd,
+{
+    link.as_ref().is_some_and(|node| is_red(&node.left))
+}
+
+/// 将右倾的红链接旋转为左倾
+fn rotate_left<T>(mut h: Box<Node<T>>) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    let mut x = h.right.take().unwrap();
+    h.right = x.left.take();
+    x.color = h.color;
+    h.color = RED;
+    x.left = Some(h);
+    x
+}
+
+/// 将左倾的红链接旋转为右倾
+fn rotate_right<T>(mut h: Box<Node<T>>) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    let mut x = h.left.take().unwrap();
+    h.left = x.right.take();
+    x.color = h.color;
+    h.color = RED;
+    x.right = Some(h);
+    x
+}
+
+/// 翻转节点及其两个子节点的颜色，相当于拆分或合并 2-3-4 树中的 4- 节点
+fn flip_colors<T>(h: &mut Node<T>)
+where
+    T: PartialOrd,
+{
+    h.color = !h.color;
+    for child in [&mut h.left, &mut h.right].into_iter().flatten() {
+        child.color = !child.color;
+    }
+}
+
+/// 自底向上恢复左倾红黑树的性质
+fn fix_up<T>(mut h: Box<Node<T>>) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    if is_red(&h.right) && !is_red(&h.left) {
+        h = rotate_left(h);
+    }
+    if is_red(&h.left) && is_left_left_red(&h.left) {
+        h = rotate_right(h);
+    }
+    if is_red(&h.left) && is_red(&h.right) {
+        flip_colors(&mut h);
+    }
+    h
+}
+
+/// 假设 h 为红色且 h.left 和 h.left.left 都是黑色，将 h.left 或其子节点变为红色
+fn move_red_left<T>(mut h: Box<Node<T>>) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    flip_colors(&mut h);
+    if is_left_left_red(&h.right) {
+        h.right = h.right.take().map(rotate_right);
+        h = rotate_left(h);
+        flip_colors(&mut h);
+    }
+    h
+}
+
+/// 假设 h 为红色且 h.right 和 h.right.left 都是黑色，将 h.right 或其子节点变为红色
+fn move_red_right<T>(mut h: Box<Node<T>>) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    flip_colors(&mut h);
+    if is_left_left_red(&h.left) {
+        h = rotate_right(h);
+        flip_colors(&mut h);
+    }
+    h
+}
+
+fn insert<T>(link: Link<T>, val: T) -> Box<Node<T>>
+where
+    T: PartialOrd,
+{
+    let Some(mut h) = link else {
+        return Box::new(Node::new(val));
+    };
+
+    if val < h.val {
+        h.left = Some(insert(h.left.take(), val));
+    } else {
+        h.right = Some(insert(h.right.take(), val));
+    }
+    fix_up(h)
+}
+
+fn delete_min<T>(mut h: Box<Node<T>>) -> (Link<T>, T)
+where
+    T: PartialOrd,
+{
+    // 左倾红黑树中没有左子节点的节点也没有右子节点
+    if h.left.is_none() {
+        return (None, h.val);
+    }
+
+    if !is_red(&h.left) && !is_left_left_red(&h.left) {
+        h = move_red_left(h);
+    }
+    let (left, min) = delete_min(h.left.take().unwrap());
+    h.left = left;
+    (Some(fix_up(h)), min)
+}
+
+fn delete_max<T>(mut h: Box<Node<T>>) -> (Link<T>, T)
+where
+    T: PartialOrd,
+{
+    if is_red(&h.left) {
+        h = rotate_right(h);
+    }
+    if h.right.is_none() {
+        return (None, h.val);
+    }
+
+    if !is_red(&h.right) && !is_left_left_red(&h.right) {
+        h = move_red_right(h);
+    }
+    let (right, max) = delete_max(h.right.take().unwrap());
+    h.right = right;
+    (Some(fix_up(h)), max)
+}
+
+/// 删除子树中等于指定值的一个节点，要求子树中存在该值
+fn delete<T>(mut h: Box<Node<T>>, val: &T) -> (Link<T>, T)
+where
+    T: PartialOrd,
+{
+    let removed;
+    if *val < h.val {
+        if !is_red(&h.left) && !is_left_left_red(&h.left) {
+            h = move_red_left(h);
+        }
+        let (left, val) = delete(h.left.take().unwrap(), val);
+        h.left = left;
+        removed = val;
+    } else {
+        if is_red(&h.left) {
+            h = rotate_right(h);
+        }
+        if *val == h.val && h.right.is_none() {
+            return (None, h.val);
+        }
+        if !is_red(&h.right) && !is_left_left_red(&h.right) {
+            h = move_red_right(h);
+        }
+        if *val == h.val {
+            // 用后继节点替换被删除的节点
+            let (right, min) = delete_min(h.right.take().unwrap());
+            h.right = right;
+            removed = mem::replace(&mut h.val, min);
+        } else {
+            let (right, val) = delete(h.right.take().unwrap(), val);
+            h.right = right;
+            removed = val;
+        }
+    }
+    (Some(fix_up(h)), removed)
+}
+
+/// 左倾红黑树
+///
+/// Sedgewick 提出的红黑树变种，红链接只能左倾且不能有两条连续的红链接，与 2-3 树一一对应。
+/// 从根节点到任意空链接的路径上黑链接的数量相同，树的高度不超过 2logn，
+/// 插入、删除和查询的时间复杂度均为 O(logn)。相比于 AVL 树，平衡条件更宽松，插入和删除时的旋转更少。
+pub struct RedBlackTree<T>
+where
+    T: PartialOrd,
+{
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T> RedBlackTree<T>
+where
+    T: PartialOrd,
+{
+    /// 创建一个空的红黑树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let tree: RedBlackTree<i32> = RedBlackTree::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// 获取元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 判断红黑树是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let tree: RedBlackTree<i32> = RedBlackTree::new();
+    ///
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 向红黑树添加一个元素，时间复杂度为 O(logn)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    ///
+    /// tree.insert(1);
+    /// ```
+    pub fn insert(&mut self, val: T) {
+        let mut root = insert(self.root.take(), val);
+        root.color = BLACK;
+        self.root = Some(root);
+        self.len += 1;
+    }
+
+    /// 删除并返回最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_min(), Some(1));
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        let mut root = self.root.take()?;
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = RED;
+        }
+
+        let (root, min) = delete_min(root);
+        self.set_root(root);
+        Some(min)
+    }
+
+    /// 删除并返回最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_max(), Some(2));
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        let mut root = self.root.take()?;
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = RED;
+        }
+
+        let (root, max) = delete_max(root);
+        self.set_root(root);
+        Some(max)
+    }
+
+    /// 删除一个等于指定值的元素，返回被删除的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.remove(&1), Some(1));
+    /// assert_eq!(tree.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, val: &T) -> Option<T> {
+        if !self.search(val) {
+            return None;
+        }
+
+        let mut root = self.root.take()?;
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = RED;
+        }
+
+        let (root, removed) = delete(root, val);
+        self.set_root(root);
+        Some(removed)
+    }
+
+    /// 删除元素后设置新的根节点，根节点总是黑色
+    fn set_root(&mut self, mut root: Link<T>) {
+        if let Some(node) = &mut root {
+            node.color = BLACK;
+        }
+        self.root = root;
+        self.len -= 1;
+    }
+
+    /// 查询红黑树中是否存在指定值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert!(tree.search(&1));
+    /// assert!(!tree.search(&2));
+    /// ```
+    pub fn search(&self, val: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match val.partial_cmp(&node.val) {
+                Some(Ordering::Less) => link = &node.left,
+                Some(Ordering::Greater) => link = &node.right,
+                Some(Ordering::Equal) => return true,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// 获取最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取不大于指定值的最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.floor(&2), Some(&1));
+    /// assert_eq!(tree.floor(&0), None);
+    /// ```
+    pub fn floor(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Greater => link = &node.left,
+                Ordering::Less => {
+                    result = Some(&node.val);
+                    link = &node.right;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取不小于指定值的最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.ceil(&2), Some(&3));
+    /// assert_eq!(tree.ceil(&4), None);
+    /// ```
+    pub fn ceil(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Less => link = &node.right,
+                Ordering::Greater => {
+                    result = Some(&node.val);
+                    link = &node.left;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取按从小到大的顺序迭代元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::RedBlackTree;
+    /// let mut tree = RedBlackTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            len: self.len,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T> Default for RedBlackTree<T>
+where
+    T: PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RedBlackTree<T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// 中序遍历的迭代器
+pub struct Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    stack: Vec<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.len -= 1;
+        Some(&node.val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> where T: PartialOrd {}
+
+impl<T> FusedIterator for Iter<'_, T> where T: PartialOrd {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::random_vec;
+    use alloc::vec::Vec;
+
+    /// 检查左倾红黑树的性质：
+    /// 有序、红链接只能左倾、没有连续的红链接、所有空链接的黑高度相同，返回子树的黑高度
+    fn check_node<T>(link: &Link<T>, lo: Option<&T>, hi: Option<&T>) -> usize
+    where
+        T: PartialOrd,
+    {
+        let Some(node) = link else {
+            return 0;
+        };
+        assert!(lo.is_none_or(|lo| *lo <= node.val));
+        assert!(hi.is_none_or(|hi| node.val <= *hi));
+        assert!(!is_red(&node.right), "right-leaning red link");
+        if node.color == RED {
+            assert!(!is_red(&node.left), "two red links in a row");
+        }
+
+        let left = check_node(&node.left, lo, Some(&node.val));
+        let right = check_node(&node.right, Some(&node.val), hi);
+        assert_eq!(left, right, "unbalanced black height");
+        left + (node.color == BLACK) as usize
+    }
+
+    fn check<T>(tree: &RedBlackTree<T>)
+    where
+        T: PartialOrd,
+    {
+        assert!(!is_red(&tree.root), "red root");
+        check_node(&tree.root, None, None);
+        assert_eq!(tree.iter().count(), tree.len());
+    }
+
+    fn prepare_tree() -> RedBlackTree<i32> {
+        let mut result = RedBlackTree::new();
+        for val in [1, 2, 3, 5, 6, 7] {
+            result.insert(val);
+        }
+        result
+    }
+
+    #[test]
+    fn empty() {
+        let mut tree: RedBlackTree<i32> = RedBlackTree::new();
+        assert!(tree.is_empty());
+        assert!(!tree.search(&1));
+        assert_eq!(tree.max(), None);
+        assert_eq!(tree.ceil(&1), None);
+        assert_eq!(tree.remove(&1), None);
+        assert_eq!(tree.pop_min(), None);
+        assert_eq!(tree.pop_max(), None);
+    }
+
+    #[test]
+    fn search_and_bounds() {
+        let tree = prepare_tree();
+        check(&tree);
+        assert!(tree.search(&1));
+        assert!(!tree.search(&4));
+        assert_eq!(tree.max(), Some(&7));
+        assert_eq!(tree.min(), Some(&1));
+        assert_eq!(tree.floor(&4), Some(&3));
+        assert_eq!(tree.ceil(&4), Some(&5));
+    }
+
+    #[test]
+    fn delete() {
+        let mut tree = prepare_tree();
+        assert_eq!(tree.pop_min(), Some(1));
+        check(&tree);
+        assert_eq!(tree.pop_max(), Some(7));
+        check(&tree);
+        assert_eq!(tree.remove(&4), None);
+        assert_eq!(tree.remove(&3), Some(3));
+        check(&tree);
+        assert!(tree.iter().eq(&[2, 5, 6]));
+    }
+
+    #[test]
+    fn repeat() {
+        let mut tree = RedBlackTree::new();
+        for val in [2, 2, 1, 2, 3, 2] {
+            tree.insert(val);
+            check(&tree);
+        }
+        assert_eq!(tree.remove(&2), Some(2));
+        assert_eq!(tree.remove(&2), Some(2));
+        check(&tree);
+        assert!(tree.iter().eq(&[1, 2, 2, 3]));
+    }
+
+    #[test]
+    fn sorted_insert() {
+        let n = 100_000;
+        let mut tree = RedBlackTree::new();
+        for val in 0..n {
+            tree.insert(val);
+        }
+        check(&tree);
+        assert!(tree.iter().copied().eq(0..n));
+
+        for val in (0..n).rev() {
+            assert_eq!(tree.pop_max(), Some(val));
+            if val % 10_000 == 0 {
+                check(&tree);
+            }
+        }
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn random() {
+        let mut tree = RedBlackTree::new();
+        let mut expected = Vec::new();
+        let ops = random_vec(3000, 52);
+        for (idx, val) in random_vec(3000, 53).into_iter().enumerate() {
+            let val = val % 300;
+            match ops[idx] % 5 {
+                0 => {
+                    let pos = expected.iter().position(|it| *it == val);
+                    assert_eq!(tree.remove(&val), pos.map(|pos| expected.remove(pos)));
+                }
+                1 => {
+                    let min = (!expected.is_empty()).then(|| expected.remove(0));
+                    assert_eq!(tree.pop_min(), min);
+                }
+                2 => assert_eq!(tree.pop_max(), expected.pop()),
+                _ => {
+                    tree.insert(val);
+                    expected.push(val);
+                    expected.sort();
+                }
+            }
+            check(&tree);
+        }
+        assert!(tree.iter().eq(expected.iter()));
+    }
+}
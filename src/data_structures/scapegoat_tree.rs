@@ -0,0 +1,706 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+struct Node<T>
+where
+    T: PartialOrd,
+{
+    val: T,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T>
+where
+    T: PartialOrd,
+{
+    fn new(val: T) -> Self {
+        Self {
+            val,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+/// 统计子树的节点数量
+fn count<T>(link: &Link<T>) -> usize
+where
+    T: PartialOrd,
+{
+    let mut result = 0;
+    let mut stack: Vec<&Node<T>> = link.as_deref().into_iter().collect();
+    while let Some(node) = stack.pop() {
+        result += 1;
+        stack.extend(node.left.as_deref());
+        stack.extend(node.right.as_deref());
+    }
+    result
+}
+
+/// 允许的最大节点深度 floor(log(1/alpha, n))，不依赖浮点数的对数运算
+fn depth_limit(len: usize, alpha: f64) -> usize {
+    let mut result = 0;
+    let mut bound = 1.0 / alpha;
+    while bound <= len as f64 {
+        bound /= alpha;
+        result += 1;
+    }
+    result
+}
+
+/// 将子树按中序展开为节点数组，节点的子节点均被清空
+fn flatten<T>(link: Link<T>, result: &mut Vec<Option<Box<Node<T>>>>)
+where
+    T: PartialOrd,
+{
+    let mut stack = Vec::new();
+    let mut link = link;
+    loop {
+        while let Some(mut node) = link {
+            link = node.left.take();
+            stack.push(node);
+        }
+        let Some(mut node) = stack.pop() else {
+            return;
+        };
+        link = node.right.take();
+        result.push(Some(node));
+    }
+}
+
+/// 将有序的节点数组构造为完全平衡的子树
+fn build<T>(nodes: &mut [Option<Box<Node<T>>>]) -> Link<T>
+where
+    T: PartialOrd,
+{
+    if nodes.is_empty() {
+        return None;
+    }
+
+    let (left, rest) = nodes.split_at_mut(nodes.len() / 2);
+    let (mid, right) = rest.split_first_mut().unwrap();
+    let mut node = mid.take().unwrap();
+    node.left = build(left);
+    node.right = build(right);
+    Some(node)
+}
+
+/// 将子树重建为完全平衡的子树
+fn rebuild<T>(link: &mut Link<T>, len: usize)
+where
+    T: PartialOrd,
+{
+    let mut nodes = Vec::with_capacity(len);
+    flatten(link.take(), &mut nodes);
+    *link = build(&mut nodes);
+}
+
+/// 插入节点并在回溯时寻找替罪羊
+///
+/// 新节点的深度超过 limit 时返回新节点所在子树的大小，由上层节点继续判断是否为替罪羊；
+/// 找到替罪羊并重建后或无需重建时返回 None。
+fn insert<T>(
+    link: &mut Link<T>,
+    node: Box<Node<T>>,
+    depth: usize,
+    limit: usize,
+    alpha: f64,
+) -> Option<usize>
+where
+    T: PartialOrd,
+{
+    let Some(current) = link else {
+        *link = Some(node);
+        return (depth > limit).then_some(1);
+    };
+
+    let (child_size, sibling) = if node.val < current.val {
+        (
+            insert(&mut current.left, node, depth + 1, limit, alpha)?,
+            &current.right,
+        )
+    } else {
+        (
+            insert(&mut current.right, node, depth + 1, limit, alpha)?,
+            &current.left,
+        )
+    };
+
+    let size = child_size + count(sibling) + 1;
+    if child_size as f64 > alpha * size as f64 {
+        rebuild(link, size);
+        return None;
+    }
+    Some(size)
+}
+
+/// 删除子树中的最小节点并返回
+fn take_min<T>(link: &mut Link<T>) -> Option<Box<Node<T>>>
+where
+    T: PartialOrd,
+{
+    let mut link = link;
+    while link.as_ref()?.left.is_some() {
+        link = &mut link.as_mut().unwrap().left;
+    }
+    let mut node = link.take()?;
+    *link = node.right.take();
+    Some(node)
+}
+
+/// 删除子树中的最大节点并返回
+fn take_max<T>(link: &mut Link<T>) -> Option<Box<Node<T>>>
+where
+    T: PartialOrd,
+{
+    let mut link = link;
+    while link.as_ref()?.right.is_some() {
+        link = &mut link.as_mut().unwrap().right;
+    }
+    let mut node = link.take()?;
+    *link = node.left.take();
+    Some(node)
+}
+
+/// 替罪羊树
+///
+/// 节点中不保存任何平衡信息，插入后若新节点的深度超过 log(1/alpha, n)，
+/// 则沿插入路径向上找到第一个子树大小超过 alpha 倍的节点（替罪羊），将以它为根的子树重建为完全平衡的二叉树；
+/// 删除使元素数量降到历史最大值的 alpha 倍以下时重建整棵树。
+/// 树的高度不超过 log(1/alpha, n) + 1，插入和删除的均摊时间复杂度为 O(logn)，查询的最坏时间复杂度为 O(logn)。
+///
+/// alpha 越接近 0.5 树越平衡、查询越快，但重建越频繁；越接近 1 则相反。
+pub struct ScapegoatTree<T>
+where
+    T: PartialOrd,
+{
+    root: Link<T>,
+    len: usize,
+    /// 上次重建整棵树以来元素数量的最大值
+    max_len: usize,
+    alpha: f64,
+}
+
+impl<T> ScapegoatTree<T>
+where
+    T: PartialOrd,
+{
+    /// 创建一个 alpha 为 2/3 的空替罪羊树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let tree: ScapegoatTree<i32> = ScapegoatTree::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::with_alpha(2.0 / 3.0)
+    }
+
+    /// 创建一个使用指定 alpha 的空替罪羊树
+    ///
+    /// # Panics
+    ///
+    /// alpha 不在 [0.5, 1) 范围内时 panic。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let tree: ScapegoatTree<i32> = ScapegoatTree::with_alpha(0.75);
+    ///
+    /// assert_eq!(tree.alpha(), 0.75);
+    /// ```
+    pub fn with_alpha(alpha: f64) -> Self {
+        assert!(
+            (0.5..1.0).contains(&alpha),
+            "alpha (is {alpha}) should be in [0.5, 1)"
+        );
+        Self {
+            root: None,
+            len: 0,
+            max_len: 0,
+            alpha,
+        }
+    }
+
+    /// 获取平衡因子 alpha
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let tree: ScapegoatTree<i32> = ScapegoatTree::new();
+    ///
+    /// assert_eq!(tree.alpha(), 2.0 / 3.0);
+    /// ```
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// 获取元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 判断替罪羊树是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let tree: ScapegoatTree<i32> = ScapegoatTree::new();
+    ///
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 获取树的高度，空树的高度为 0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// assert_eq!(tree.height(), 0);
+    ///
+    /// for val in 0..1000 {
+    ///     tree.insert(val);
+    /// }
+    /// assert!(tree.height() <= 18);
+    /// ```
+    pub fn height(&self) -> usize {
+        let mut result = 0;
+        let mut stack: Vec<(&Node<T>, usize)> = self
+            .root
+            .as_deref()
+            .map(|node| (node, 1))
+            .into_iter()
+            .collect();
+        while let Some((node, depth)) = stack.pop() {
+            result = result.max(depth);
+            for child in [&node.left, &node.right].into_iter().flatten() {
+                stack.push((child, depth + 1));
+            }
+        }
+        result
+    }
+
+    /// 向替罪羊树添加一个元素，均摊时间复杂度为 O(logn)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    ///
+    /// tree.insert(1);
+    /// ```
+    pub fn insert(&mut self, val: T) {
+        self.len += 1;
+        self.max_len = self.max_len.max(self.len);
+
+        let limit = depth_limit(self.len, self.alpha);
+        let node = Box::new(Node::new(val));
+        if insert(&mut self.root, node, 0, limit, self.alpha).is_some() {
+            // 深度超限时必然存在替罪羊，这里只是兜底
+            rebuild(&mut self.root, self.len);
+        }
+    }
+
+    /// 删除元素后按需重建整棵树
+    fn after_remove(&mut self) {
+        self.len -= 1;
+        if self.len as f64 <= self.alpha * self.max_len as f64 {
+            rebuild(&mut self.root, self.len);
+            self.max_len = self.len;
+        }
+    }
+
+    /// 查询替罪羊树中是否存在指定值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert!(tree.search(&1));
+    /// assert!(!tree.search(&2));
+    /// ```
+    pub fn search(&self, val: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match val.partial_cmp(&node.val) {
+                Some(Ordering::Less) => link = &node.left,
+                Some(Ordering::Greater) => link = &node.right,
+                Some(Ordering::Equal) => return true,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// 获取最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取不大于指定值的最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.floor(&2), Some(&1));
+    /// assert_eq!(tree.floor(&0), None);
+    /// ```
+    pub fn floor(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Greater => link = &node.left,
+                Ordering::Less => {
+                    result = Some(&node.val);
+                    link = &node.right;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取不小于指定值的最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.ceil(&2), Some(&3));
+    /// assert_eq!(tree.ceil(&4), None);
+    /// ```
+    pub fn ceil(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Less => link = &node.right,
+                Ordering::Greater => {
+                    result = Some(&node.val);
+                    link = &node.left;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 删除一个等于指定值的元素，返回被删除的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.remove(&1), Some(1));
+    /// assert_eq!(tree.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, val: &T) -> Option<T> {
+        let mut link = &mut self.root;
+        loop {
+            match val.partial_cmp(&link.as_ref()?.val)? {
+                Ordering::Less => link = &mut link.as_mut().unwrap().left,
+                Ordering::Greater => link = &mut link.as_mut().unwrap().right,
+                Ordering::Equal => break,
+            }
+        }
+
+        let mut node = link.take()?;
+        *link = match take_min(&mut node.right) {
+            None => node.left.take(),
+            // 用后继节点替换被删除的节点
+            Some(mut successor) => {
+                successor.left = node.left.take();
+                successor.right = node.right.take();
+                Some(successor)
+            }
+        };
+        self.after_remove();
+        Some(node.val)
+    }
+
+    /// 删除并返回最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_min(), Some(1));
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        let node = take_min(&mut self.root)?;
+        self.after_remove();
+        Some(node.val)
+    }
+
+    /// 删除并返回最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.pop_max(), Some(2));
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        let node = take_max(&mut self.root)?;
+        self.after_remove();
+        Some(node.val)
+    }
+
+    /// 只保留满足条件的元素，按从小到大的顺序访问每个元素，之后重建整棵树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// for val in 1..=6 {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// tree.retain(|val| val % 2 == 0);
+    /// assert!(tree.iter().eq(&[2, 4, 6]));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut nodes = Vec::with_capacity(self.len);
+        flatten(self.root.take(), &mut nodes);
+        nodes.retain(|node| f(&node.as_ref().unwrap().val));
+
+        self.root = build(&mut nodes);
+        self.len = nodes.len();
+        self.max_len = self.len;
+    }
+
+    /// 获取按从小到大的顺序迭代元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            len: self.len,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T> Default for ScapegoatTree<T>
+where
+    T: PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ScapegoatTree<T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// 中序遍历的迭代器
+pub struct Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    stack: Vec<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.len -= 1;
+        Some(&node.val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> where T: PartialOrd {}
+
+impl<T> FusedIterator for Iter<'_, T> where T: PartialOrd {}
+
+#[cfg(test)]
+mod tests {
+    use super::{count, depth_limit, ScapegoatTree};
+    use crate::data_structures::bst_tests::bst_tests;
+    use alloc::vec::Vec;
+
+    bst_tests!(ScapegoatTree);
+
+    /// 检查元素有序、数量正确且高度不超过 log(1/alpha, max_len) + 1，返回按从小到大的顺序排列的元素
+    fn sorted_values<T>(tree: &ScapegoatTree<T>) -> Vec<T>
+    where
+        T: PartialOrd + Copy,
+    {
+        let result: Vec<T> = tree.iter().copied().collect();
+        assert!(result.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(result.len(), tree.len());
+        assert_eq!(count(&tree.root), tree.len());
+        assert!(tree.height() <= depth_limit(tree.max_len, tree.alpha) + 1);
+        result
+    }
+
+    #[test]
+    fn test_iter() {
+        let tree = prepare_tree();
+        assert!(tree.iter().eq(&[1, 2, 3, 5, 6, 7]));
+        assert_eq!(tree.iter().len(), 6);
+        assert!((&tree).into_iter().eq(tree.iter()));
+    }
+
+    #[test]
+    fn test_sorted_insert() {
+        for alpha in [0.5, 2.0 / 3.0, 0.9] {
+            let mut tree = ScapegoatTree::with_alpha(alpha);
+            for val in 0..10_000 {
+                tree.insert(val);
+            }
+            assert!(tree.iter().copied().eq(0..10_000));
+            assert!(tree.height() <= depth_limit(10_000, alpha) + 1);
+        }
+    }
+
+    #[test]
+    fn test_rebuild_after_remove() {
+        let mut tree = ScapegoatTree::new();
+        for val in 0..1000 {
+            tree.insert(val);
+        }
+        for val in 0..900 {
+            assert_eq!(tree.remove(&val), Some(val));
+        }
+        assert!(tree.max_len < 1000);
+        assert!(tree.height() <= depth_limit(100, tree.alpha()) + 1);
+        assert_eq!(sorted_values(&tree), (900..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_alpha() {
+        ScapegoatTree::<i32>::with_alpha(0.4);
+    }
+}
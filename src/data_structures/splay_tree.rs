@@ -0,0 +1,558 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+struct Node<T>
+where
+    T: PartialOrd,
+{
+    val: T,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T>
+where
+    T: PartialOrd,
+{
+    fn new(val: T) -> Self {
+        Self {
+            val,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+/// 自顶向下伸展，将目标节点或查找路径上最后访问的节点移动到根节点
+///
+/// cmp 返回目标相对于节点的位置，返回 `Equal` 或 `None` 时停止。
+/// 查找路径上比目标小的节点依次挂到左树的最右侧，比目标大的节点依次挂到右树的最左侧，
+/// 最后将左右两棵树作为新根节点的子树，整个过程不使用递归。
+fn splay_by<T, F>(mut root: Box<Node<T>>, cmp: F) -> Box<Node<T>>
+where
+    T: PartialOrd,
+    F: Fn(&T) -> Option<Ordering>,
+{
+    // 按挂接顺序记录左树和右树的节点
+    let mut left_nodes = Vec::new();
+    let mut right_nodes = Vec::new();
+    loop {
+        match cmp(&root.val) {
+            Some(Ordering::Less) => {
+                let Some(mut left) = root.left.take() else {
+                    break;
+                };
+                if cmp(&left.val) == Some(Ordering::Less) {
+                    // zig-zig：先右旋
+                    root.left = left.right.take();
+                    left.right = Some(root);
+                    root = left;
+                    let Some(next) = root.left.take() else {
+                        break;
+                    };
+                    right_nodes.push(root);
+                    root = next;
+                } else {
+                    right_nodes.push(root);
+                    root = left;
+                }
+            }
+            Some(Ordering::Greater) => {
+                let Some(mut right) = root.right.take() else {
+                    break;
+                };
+                if cmp(&right.val) == Some(Ordering::Greater) {
+                    // zag-zag：先左旋
+                    root.right = right.left.take();
+                    right.left = Some(root);
+                    root = right;
+                    let Some(next) = root.right.take() else {
+                        break;
+                    };
+                    left_nodes.push(root);
+                    root = next;
+                } else {
+                    left_nodes.push(root);
+                    root = right;
+                }
+            }
+            _ => break,
+        }
+    }
+
+    // 组装：左树的最右侧接上根节点的左子树，右树的最左侧接上根节点的右子树
+    let mut left = root.left.take();
+    for mut node in left_nodes.into_iter().rev() {
+        node.right = left;
+        left = Some(node);
+    }
+    let mut right = root.right.take();
+    for mut node in right_nodes.into_iter().rev() {
+        node.left = right;
+        right = Some(node);
+    }
+    root.left = left;
+    root.right = right;
+    root
+}
+
+/// 伸展树
+///
+/// 每次访问后都将被访问的节点伸展到根节点，频繁访问的元素始终靠近根节点。
+/// 不保证树的平衡，单次操作最坏为 O(n)，但任意 m 次操作的总时间复杂度为 O(mlogn)，
+/// 即均摊时间复杂度为 O(logn)，访问分布越集中，实际开销越小。
+///
+/// 查询操作同样会改变树的结构，因此需要可变引用。
+pub struct SplayTree<T>
+where
+    T: PartialOrd,
+{
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T> SplayTree<T>
+where
+    T: PartialOrd,
+{
+    /// 创建一个空的伸展树
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let tree: SplayTree<i32> = SplayTree::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// 获取元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 判断伸展树是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let tree: SplayTree<i32> = SplayTree::new();
+    ///
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 将最接近指定值的节点伸展到根节点，返回根节点与指定值的比较结果
+    fn splay(&mut self, val: &T) -> Option<Ordering> {
+        let root = self.root.take()?;
+        let root = splay_by(root, |it| val.partial_cmp(it));
+        let result = root.val.partial_cmp(val);
+        self.root = Some(root);
+        result
+    }
+
+    /// 向伸展树添加一个元素，新元素成为根节点，均摊时间复杂度为 O(logn)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    ///
+    /// tree.insert(1);
+    /// ```
+    pub fn insert(&mut self, val: T) {
+        let mut node = Box::new(Node::new(val));
+        if let Some(mut root) = self.root.take() {
+            root = splay_by(root, |it| node.val.partial_cmp(it));
+            if node.val < root.val {
+                node.left = root.left.take();
+                node.right = Some(root);
+            } else {
+                node.right = root.right.take();
+                node.left = Some(root);
+            }
+        }
+        self.root = Some(node);
+        self.len += 1;
+    }
+
+    /// 查询伸展树中是否存在指定值，找到的元素成为根节点
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert!(tree.search(&1));
+    /// assert!(!tree.search(&2));
+    /// ```
+    pub fn search(&mut self, val: &T) -> bool {
+        self.splay(val) == Some(Ordering::Equal)
+    }
+
+    /// 删除一个等于指定值的元素，返回被删除的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1);
+    ///
+    /// assert_eq!(tree.remove(&1), Some(1));
+    /// assert_eq!(tree.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, val: &T) -> Option<T> {
+        if self.splay(val) != Some(Ordering::Equal) {
+            return None;
+        }
+
+        let mut root = self.root.take()?;
+        self.root = match root.left.take() {
+            None => root.right.take(),
+            Some(left) => {
+                // 左子树的最大值伸展到根节点后没有右子节点
+                let mut left = splay_by(left, |_| Some(Ordering::Greater));
+                left.right = root.right.take();
+                Some(left)
+            }
+        };
+        self.len -= 1;
+        Some(root.val)
+    }
+
+    /// 获取最大值，最大值成为根节点
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.max(), Some(&2));
+    /// ```
+    pub fn max(&mut self) -> Option<&T> {
+        let root = self.root.take()?;
+        let root = self
+            .root
+            .insert(splay_by(root, |_| Some(Ordering::Greater)));
+        Some(&root.val)
+    }
+
+    /// 获取最小值，最小值成为根节点
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(tree.min(), Some(&1));
+    /// ```
+    pub fn min(&mut self) -> Option<&T> {
+        let root = self.root.take()?;
+        let root = self.root.insert(splay_by(root, |_| Some(Ordering::Less)));
+        Some(&root.val)
+    }
+
+    /// 获取不大于指定值的最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.floor(&2), Some(&1));
+    /// assert_eq!(tree.floor(&0), None);
+    /// ```
+    pub fn floor(&mut self, val: &T) -> Option<&T> {
+        match self.splay(val)? {
+            Ordering::Less | Ordering::Equal => self.root.as_ref().map(|node| &node.val),
+            // 根节点是大于指定值的最小值，所求为左子树的最大值
+            Ordering::Greater => {
+                let mut node = self.root.as_ref()?.left.as_ref()?;
+                while let Some(right) = &node.right {
+                    node = right;
+                }
+                Some(&node.val)
+            }
+        }
+    }
+
+    /// 获取不小于指定值的最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1);
+    /// tree.insert(3);
+    ///
+    /// assert_eq!(tree.ceil(&2), Some(&3));
+    /// assert_eq!(tree.ceil(&4), None);
+    /// ```
+    pub fn ceil(&mut self, val: &T) -> Option<&T> {
+        match self.splay(val)? {
+            Ordering::Greater | Ordering::Equal => self.root.as_ref().map(|node| &node.val),
+            // 根节点是小于指定值的最大值，所求为右子树的最小值
+            Ordering::Less => {
+                let mut node = self.root.as_ref()?.right.as_ref()?;
+                while let Some(left) = &node.left {
+                    node = left;
+                }
+                Some(&node.val)
+            }
+        }
+    }
+
+    /// 获取按从小到大的顺序迭代元素的迭代器，迭代不改变树的结构
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// for val in [2, 3, 1] {
+    ///     tree.insert(val);
+    /// }
+    ///
+    /// assert!(tree.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            len: self.len,
+        };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T> Default for SplayTree<T>
+where
+    T: PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for SplayTree<T>
+where
+    T: PartialOrd,
+{
+    /// 伸展树可能退化为很深的链表，逐个释放节点以避免递归释放导致栈溢出
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SplayTree<T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// 中序遍历的迭代器
+pub struct Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    stack: Vec<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: PartialOrd,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.len -= 1;
+        Some(&node.val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> where T: PartialOrd {}
+
+impl<T> FusedIterator for Iter<'_, T> where T: PartialOrd {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::random_vec;
+    use alloc::vec::Vec;
+
+    fn check<T>(tree: &SplayTree<T>)
+    where
+        T: PartialOrd,
+    {
+        let values: Vec<&T> = tree.iter().collect();
+        assert_eq!(values.len(), tree.len());
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    fn root<T>(tree: &SplayTree<T>) -> &T
+    where
+        T: PartialOrd,
+    {
+        &tree.root.as_ref().unwrap().val
+    }
+
+    fn prepare_tree() -> SplayTree<i32> {
+        let mut result = SplayTree::new();
+        for val in [1, 2, 3, 5, 6, 7] {
+            result.insert(val);
+        }
+        result
+    }
+
+    #[test]
+    fn empty() {
+        let mut tree: SplayTree<i32> = SplayTree::new();
+        assert!(tree.is_empty());
+        assert!(!tree.search(&1));
+        assert_eq!(tree.max(), None);
+        assert_eq!(tree.floor(&1), None);
+        assert_eq!(tree.remove(&1), None);
+    }
+
+    #[test]
+    fn queries() {
+        let mut tree = prepare_tree();
+        assert!(tree.search(&1));
+        assert_eq!(root(&tree), &1);
+        assert!(!tree.search(&4));
+        check(&tree);
+        assert_eq!(tree.floor(&4), Some(&3));
+        assert_eq!(tree.floor(&0), None);
+        assert_eq!(tree.ceil(&4), Some(&5));
+        assert_eq!(tree.ceil(&8), None);
+        assert_eq!(tree.max(), Some(&7));
+        assert_eq!(root(&tree), &7);
+        assert_eq!(tree.min(), Some(&1));
+        check(&tree);
+    }
+
+    #[test]
+    fn remove() {
+        let mut tree = prepare_tree();
+        assert_eq!(tree.remove(&4), None);
+        assert_eq!(tree.remove(&3), Some(3));
+        assert_eq!(tree.remove(&7), Some(7));
+        assert_eq!(tree.remove(&1), Some(1));
+        check(&tree);
+        assert!(tree.iter().eq(&[2, 5, 6]));
+    }
+
+    #[test]
+    fn repeat() {
+        let mut tree = SplayTree::new();
+        for val in [2, 2, 1, 2, 3, 2] {
+            tree.insert(val);
+        }
+        assert_eq!(tree.remove(&2), Some(2));
+        assert_eq!(tree.remove(&2), Some(2));
+        check(&tree);
+        assert!(tree.iter().eq(&[1, 2, 2, 3]));
+    }
+
+    #[test]
+    fn random() {
+        let mut tree = SplayTree::new();
+        let mut expected = Vec::new();
+        let ops = random_vec(3000, 60);
+        for (idx, val) in random_vec(3000, 61).into_iter().enumerate() {
+            let val = val % 300;
+            match ops[idx] % 4 {
+                0 => {
+                    let pos = expected.iter().position(|it| *it == val);
+                    assert_eq!(tree.remove(&val), pos.map(|pos| expected.remove(pos)));
+                }
+                1 => {
+                    let floor = expected.iter().rev().find(|it| **it <= val);
+                    assert_eq!(tree.floor(&val), floor);
+                }
+                _ => {
+                    tree.insert(val);
+                    expected.push(val);
+                    expected.sort();
+                }
+            }
+        }
+        check(&tree);
+        assert!(tree.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn sorted_insert() {
+        // 顺序插入后树退化为链表，伸展与释放都不能依赖递归
+        let n = 1_000_000;
+        let mut tree = SplayTree::new();
+        for val in 0..n {
+            tree.insert(val);
+        }
+        assert!(tree.search(&0));
+        assert_eq!(root(&tree), &0);
+        assert!(tree.search(&(n / 2)));
+        assert_eq!(tree.len(), n as usize);
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 /// 基于单链表实现的栈
 ///
 /// 栈内元素满足先进后出（FILO）顺序，因此在栈顶添加和删除元素的时间复杂度为 O(1)。
@@ -26,9 +28,9 @@ impl<T> Stack<T> {
     }
 
     /// 向栈中添加一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Stack;
     /// let mut stack = Stack::new();
@@ -43,15 +45,15 @@ impl<T> Stack<T> {
     }
 
     /// 从栈中移除一个元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Stack;
     /// let mut stack = Stack::new();
-    /// 
+    ///
     /// assert_eq!(stack.pop(), None);
-    /// 
+    ///
     /// stack.push(1);
     /// assert_eq!(stack.pop(), Some(1));
     /// ```
@@ -64,13 +66,13 @@ impl<T> Stack<T> {
     }
 
     /// 判断栈是否为空
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Stack;
     /// let mut stack:Stack<i32> = Stack::new();
-    /// 
+    ///
     /// assert!(stack.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
@@ -78,15 +80,15 @@ impl<T> Stack<T> {
     }
 
     /// 查看栈顶元素
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// use rust_demo::data_structures::Stack;
     /// let mut stack = Stack::new();
-    /// 
+    ///
     /// assert_eq!(stack.peek(), None);
-    /// 
+    ///
     /// stack.push(1);
     /// assert_eq!(stack.peek(), Some(&1));
     /// ```
@@ -98,11 +100,11 @@ impl<T> Stack<T> {
     }
 
     /// 获取栈元素的不可变引用迭代器
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
-    /// use rust_demo::data_structures::Stack; 
+    /// use rust_demo::data_structures::Stack;
     /// let stack:Stack<i32> = Stack::new();
     /// let iter = stack.iter();
     /// ```
@@ -113,11 +115,11 @@ impl<T> Stack<T> {
     }
 
     /// 获取栈元素的可变引用迭代器
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
-    /// use rust_demo::data_structures::Stack; 
+    /// use rust_demo::data_structures::Stack;
     /// let mut stack:Stack<i32> = Stack::new();
     /// let iter_mut = stack.iter_mut();
     /// ```
@@ -126,27 +128,32 @@ impl<T> Stack<T> {
             next: self.head.as_deref_mut(),
         }
     }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
 
     /// 获取栈元素的迭代器
-    /// 
+    ///
     /// # Example
-    /// 
+    ///
     /// ```
-    /// use rust_demo::data_structures::Stack; 
+    /// use rust_demo::data_structures::Stack;
     /// let stack:Stack<i32> = Stack::new();
     /// let into_iter = stack.into_iter();
     /// ```
-    pub fn into_iter(self) -> IntoIter<T> {
+    fn into_iter(self) -> IntoIter<T> {
         IntoIter(self)
     }
 }
 
-impl<T> Default for Stack<T> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl<T> Drop for Stack<T> {
     #![allow(unused_assignments)]
     fn drop(&mut self) {
@@ -220,7 +227,7 @@ mod tests {
         assert_eq!(stack.pop(), Some(3));
         assert_eq!(stack.pop(), Some(2));
 
-        assert_eq!(stack.is_empty(), false);
+        assert!(!stack.is_empty());
 
         stack.push(4);
         stack.push(5);
@@ -240,6 +247,6 @@ mod tests {
 
         stack.push(1);
         assert_eq!(stack.peek(), Some(&1));
-        assert_eq!(stack.is_empty(), false);
+        assert!(!stack.is_empty());
     }
 }
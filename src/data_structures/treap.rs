@@ -0,0 +1,1275 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::ops::{Bound, Index, RangeBounds};
+
+/// 树堆节点优先级的生成器
+///
+/// 树堆的期望高度依赖于优先级的随机性，实现者应返回近似均匀分布的随机数。
+/// 使用固定种子的生成器可以让树的形状在多次运行间保持一致，便于复现问题。
+pub trait PriorityGenerator {
+    /// 生成下一个节点的优先级
+    fn next_priority(&mut self) -> u64;
+
+    /// 创建一个新的生成器，拆分树堆时由拆出的部分使用
+    ///
+    /// 新生成器的随机数序列应与当前生成器不同，否则拆分后的两部分会生成相同的优先级序列。
+    fn fork(&mut self) -> Self
+    where
+        Self: Sized;
+}
+
+/// 基于 xorshift64 算法的优先级生成器，树堆默认使用的生成器
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// 使用指定的种子创建生成器，种子为 0 时使用 1 代替
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{PriorityGenerator, XorShift};
+    /// let mut a = XorShift::new(42);
+    /// let mut b = XorShift::new(42);
+    ///
+    /// assert_eq!(a.next_priority(), b.next_priority());
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed.max(1) }
+    }
+}
+
+impl Default for XorShift {
+    fn default() -> Self {
+        Self::new(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+impl PriorityGenerator for XorShift {
+    fn next_priority(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// 用当前生成器的下一个随机数作为种子创建新的生成器
+    ///
+    /// xorshift64 的输出即为其状态，种子先经过 splitmix64 的混合函数，使新生成器位于序列中的另一位置。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{PriorityGenerator, XorShift};
+    /// let mut a = XorShift::new(42);
+    /// let mut b = a.fork();
+    ///
+    /// assert_ne!(a.next_priority(), b.next_priority());
+    /// ```
+    fn fork(&mut self) -> Self {
+        let mut seed = self.next_priority().wrapping_add(0x9e37_79b9_7f4a_7c15);
+        seed = (seed ^ (seed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        seed = (seed ^ (seed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Self::new(seed ^ (seed >> 31))
+    }
+}
+
+struct Node<T> {
+    val: T,
+    priority: u64,
+    /// 以该节点为根的子树的节点数量
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn new(val: T, priority: u64) -> Self {
+        Self {
+            val,
+            priority,
+            size: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update_size(&mut self) {
+        self.size = size(&self.left) + size(&self.right) + 1;
+    }
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+/// 合并两棵子树，要求 left 中的元素都排在 right 中的元素之前
+fn merge<T>(left: Link<T>, right: Link<T>) -> Link<T> {
+    match (left, right) {
+        (None, link) | (link, None) => link,
+        (Some(mut left), Some(mut right)) => {
+            // 优先级较大的节点作为根节点
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update_size();
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update_size();
+                Some(right)
+            }
+        }
+    }
+}
+
+/// 将子树拆分为满足 is_left 和不满足 is_left 的两部分，要求 is_left 对有序的元素单调
+fn split_by<T, F>(link: Link<T>, is_left: &F) -> (Link<T>, Link<T>)
+where
+    F: Fn(&T) -> bool,
+{
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    if is_left(&node.val) {
+        let (left, right) = split_by(node.right.take(), is_left);
+        node.right = left;
+        node.update_size();
+        (Some(node), right)
+    } else {
+        let (left, right) = split_by(node.left.take(), is_left);
+        node.left = right;
+        node.update_size();
+        (left, Some(node))
+    }
+}
+
+/// 将子树拆分为前 k 个元素和其余元素两部分
+fn split_at<T>(link: Link<T>, k: usize) -> (Link<T>, Link<T>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    let left_size = size(&node.left);
+    if k <= left_size {
+        let (left, right) = split_at(node.left.take(), k);
+        node.left = right;
+        node.update_size();
+        (left, Some(node))
+    } else {
+        let (left, right) = split_at(node.right.take(), k - left_size - 1);
+        node.right = left;
+        node.update_size();
+        (Some(node), right)
+    }
+}
+
+/// 统计子树中满足 is_below 的元素数量，要求 is_below 对有序的元素单调
+fn count_below<T, F>(link: &Link<T>, is_below: F) -> usize
+where
+    F: Fn(&T) -> bool,
+{
+    let mut count = 0;
+    let mut link = link;
+    while let Some(node) = link {
+        if is_below(&node.val) {
+            count += size(&node.left) + 1;
+            link = &node.right;
+        } else {
+            link = &node.left;
+        }
+    }
+    count
+}
+
+/// 获取子树中第 k 个节点（k 从 0 开始）
+fn select<T>(link: &Link<T>, k: usize) -> Option<&Node<T>> {
+    let mut k = k;
+    let mut link = link;
+    while let Some(node) = link {
+        let left_size = size(&node.left);
+        match k.cmp(&left_size) {
+            Ordering::Less => link = &node.left,
+            Ordering::Equal => return Some(node),
+            Ordering::Greater => {
+                k -= left_size + 1;
+                link = &node.right;
+            }
+        }
+    }
+    None
+}
+
+fn select_mut<T>(link: &mut Link<T>, k: usize) -> Option<&mut Node<T>> {
+    let mut k = k;
+    let mut link = link;
+    while let Some(node) = link {
+        let left_size = size(&node.left);
+        match k.cmp(&left_size) {
+            Ordering::Less => link = &mut node.left,
+            Ordering::Equal => return Some(node),
+            Ordering::Greater => {
+                k -= left_size + 1;
+                link = &mut node.right;
+            }
+        }
+    }
+    None
+}
+
+/// 删除子树中等于指定值的一个节点，返回被删除的值
+fn remove<T>(link: &mut Link<T>, val: &T) -> Option<T>
+where
+    T: PartialOrd,
+{
+    let node = link.as_mut()?;
+    let removed = match val.partial_cmp(&node.val)? {
+        Ordering::Less => remove(&mut node.left, val)?,
+        Ordering::Greater => remove(&mut node.right, val)?,
+        Ordering::Equal => {
+            let mut node = link.take().unwrap();
+            *link = merge(node.left.take(), node.right.take());
+            return Some(node.val);
+        }
+    };
+    node.size -= 1;
+    Some(removed)
+}
+
+fn pop_min<T>(link: &mut Link<T>) -> Option<T> {
+    let node = link.as_mut()?;
+    if node.left.is_some() {
+        let min = pop_min(&mut node.left);
+        node.size -= 1;
+        return min;
+    }
+
+    let node = link.take().unwrap();
+    *link = node.right;
+    Some(node.val)
+}
+
+fn pop_max<T>(link: &mut Link<T>) -> Option<T> {
+    let node = link.as_mut()?;
+    if node.right.is_some() {
+        let max = pop_max(&mut node.right);
+        node.size -= 1;
+        return max;
+    }
+
+    let node = link.take().unwrap();
+    *link = node.left;
+    Some(node.val)
+}
+
+/// 树堆
+///
+/// 每个节点带有一个随机的优先级，节点的值满足二叉搜索树的性质，优先级满足大顶堆的性质。
+/// 树的形状与按优先级从大到小的顺序插入的二叉搜索树相同，期望高度为 O(logn)，
+/// 插入、删除、查询以及按值拆分、合并的期望时间复杂度均为 O(logn)。
+///
+/// 优先级由 [`PriorityGenerator`] 生成，默认使用 [`XorShift`]。
+pub struct Treap<T, G = XorShift>
+where
+    T: PartialOrd,
+    G: PriorityGenerator,
+{
+    root: Link<T>,
+    generator: G,
+}
+
+impl<T> Treap<T>
+where
+    T: PartialOrd,
+{
+    /// 创建一个使用默认优先级生成器的空树堆
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let treap: Treap<i32> = Treap::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::with_generator(XorShift::default())
+    }
+}
+
+impl<T, G> Treap<T, G>
+where
+    T: PartialOrd,
+    G: PriorityGenerator,
+{
+    /// 创建一个使用指定优先级生成器的空树堆
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{Treap, XorShift};
+    /// let mut treap = Treap::with_generator(XorShift::new(42));
+    /// treap.insert(1);
+    /// ```
+    pub fn with_generator(generator: G) -> Self {
+        Self {
+            root: None,
+            generator,
+        }
+    }
+
+    /// 获取元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    ///
+    /// assert_eq!(treap.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// 判断树堆是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let treap: Treap<i32> = Treap::new();
+    ///
+    /// assert!(treap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 向树堆添加一个元素，相等的元素排在已有元素之后
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    ///
+    /// treap.insert(1);
+    /// ```
+    pub fn insert(&mut self, val: T) {
+        let (left, right) = split_by(self.root.take(), &|it: &T| *it <= val);
+        let node = Box::new(Node::new(val, self.generator.next_priority()));
+        self.root = merge(merge(left, Some(node)), right);
+    }
+
+    /// 删除一个等于指定值的元素，返回被删除的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    ///
+    /// assert_eq!(treap.remove(&1), Some(1));
+    /// assert_eq!(treap.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, val: &T) -> Option<T> {
+        remove(&mut self.root, val)
+    }
+
+    /// 删除并返回最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(2);
+    /// treap.insert(1);
+    ///
+    /// assert_eq!(treap.pop_min(), Some(1));
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        pop_min(&mut self.root)
+    }
+
+    /// 删除并返回最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(2);
+    /// treap.insert(1);
+    ///
+    /// assert_eq!(treap.pop_max(), Some(2));
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        pop_max(&mut self.root)
+    }
+
+    /// 查询树堆中是否存在指定值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    ///
+    /// assert!(treap.search(&1));
+    /// assert!(!treap.search(&2));
+    /// ```
+    pub fn search(&self, val: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match val.partial_cmp(&node.val) {
+                Some(Ordering::Less) => link = &node.left,
+                Some(Ordering::Greater) => link = &node.right,
+                Some(Ordering::Equal) => return true,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// 获取最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    /// treap.insert(2);
+    ///
+    /// assert_eq!(treap.max(), Some(&2));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    /// treap.insert(2);
+    ///
+    /// assert_eq!(treap.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.val)
+    }
+
+    /// 获取不大于指定值的最大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    /// treap.insert(3);
+    ///
+    /// assert_eq!(treap.floor(&2), Some(&1));
+    /// assert_eq!(treap.floor(&0), None);
+    /// ```
+    pub fn floor(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Greater => link = &node.left,
+                Ordering::Less => {
+                    result = Some(&node.val);
+                    link = &node.right;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取不小于指定值的最小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1);
+    /// treap.insert(3);
+    ///
+    /// assert_eq!(treap.ceil(&2), Some(&3));
+    /// assert_eq!(treap.ceil(&4), None);
+    /// ```
+    pub fn ceil(&self, val: &T) -> Option<&T> {
+        let mut result = None;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match node.val.partial_cmp(val)? {
+                Ordering::Less => link = &node.right,
+                Ordering::Greater => {
+                    result = Some(&node.val);
+                    link = &node.left;
+                }
+                Ordering::Equal => return Some(&node.val),
+            }
+        }
+        result
+    }
+
+    /// 获取树堆中小于指定值的元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// for val in [1, 3, 5] {
+    ///     treap.insert(val);
+    /// }
+    ///
+    /// assert_eq!(treap.rank(&3), 1);
+    /// assert_eq!(treap.rank(&4), 2);
+    /// ```
+    pub fn rank(&self, val: &T) -> usize {
+        count_below(&self.root, |it| it < val)
+    }
+
+    /// 获取树堆中第 k 小的元素（k 从 0 开始）
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// for val in [3, 1, 5] {
+    ///     treap.insert(val);
+    /// }
+    ///
+    /// assert_eq!(treap.select(1), Some(&3));
+    /// assert_eq!(treap.select(3), None);
+    /// ```
+    pub fn select(&self, k: usize) -> Option<&T> {
+        select(&self.root, k).map(|node| &node.val)
+    }
+
+    /// 获取树堆中位于 [lo, hi] 区间内的元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// for val in [1, 3, 5, 7] {
+    ///     treap.insert(val);
+    /// }
+    ///
+    /// assert_eq!(treap.count_range(&2, &5), 2);
+    /// assert_eq!(treap.count_range(&5, &2), 0);
+    /// ```
+    pub fn count_range(&self, lo: &T, hi: &T) -> usize {
+        let below_lo = count_below(&self.root, |it| it < lo);
+        let up_to_hi = count_below(&self.root, |it| it <= hi);
+        up_to_hi.saturating_sub(below_lo)
+    }
+
+    /// 获取按从小到大的顺序迭代元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// for val in [2, 3, 1] {
+    ///     treap.insert(val);
+    /// }
+    ///
+    /// assert!(treap.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.root)
+    }
+
+    /// 获取按从小到大的顺序迭代指定范围内元素的迭代器，范围的起点大于终点时返回空的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// for val in [5, 1, 4, 2, 3] {
+    ///     treap.insert(val);
+    /// }
+    ///
+    /// assert!(treap.range(2..4).eq(&[2, 3]));
+    /// assert!(treap.range(3..).eq(&[3, 4, 5]));
+    /// ```
+    pub fn range<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<T>,
+    {
+        let is_below = |it: &T| match range.start_bound() {
+            Bound::Included(lo) => it < lo,
+            Bound::Excluded(lo) => it <= lo,
+            Bound::Unbounded => false,
+        };
+        let is_above = |it: &T| match range.end_bound() {
+            Bound::Included(hi) => it > hi,
+            Bound::Excluded(hi) => it >= hi,
+            Bound::Unbounded => false,
+        };
+
+        let up_to_end = count_below(&self.root, |it| !is_above(it));
+        let mut iter = Iter {
+            stack: Vec::new(),
+            len: up_to_end.saturating_sub(count_below(&self.root, is_below)),
+        };
+
+        // 只压入不小于范围起点的节点，迭代到剩余数量为 0 时即越过范围终点
+        let mut link = &self.root;
+        while let Some(node) = link {
+            if is_below(&node.val) {
+                link = &node.right;
+            } else {
+                iter.stack.push(node);
+                link = &node.left;
+            }
+        }
+        iter
+    }
+
+    /// 将树堆拆分为小于指定值和不小于指定值的两部分，期望时间复杂度为 O(logn)
+    ///
+    /// 不小于指定值的部分继续使用原来的优先级生成器，另一部分使用 [`PriorityGenerator::fork`] 创建的生成器。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut treap = Treap::new();
+    /// for val in 1..=5 {
+    ///     treap.insert(val);
+    /// }
+    ///
+    /// let (left, right) = treap.split(&3);
+    /// assert!(left.iter().eq(&[1, 2]));
+    /// assert!(right.iter().eq(&[3, 4, 5]));
+    /// ```
+    pub fn split(mut self, val: &T) -> (Self, Self) {
+        let (left, right) = split_by(self.root, &|it: &T| it < val);
+        let left = Self {
+            root: left,
+            generator: self.generator.fork(),
+        };
+        let right = Self {
+            root: right,
+            generator: self.generator,
+        };
+        (left, right)
+    }
+
+    /// 合并两个树堆，期望时间复杂度为 O(logn)，合并后的树堆使用 left 的优先级生成器
+    ///
+    /// # Panics
+    ///
+    /// left 中的最大值大于 right 中的最小值时 panic。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::Treap;
+    /// let mut left = Treap::new();
+    /// let mut right = Treap::new();
+    /// left.insert(1);
+    /// right.insert(2);
+    ///
+    /// let treap = Treap::merge(left, right);
+    /// assert!(treap.iter().eq(&[1, 2]));
+    /// ```
+    pub fn merge(left: Self, right: Self) -> Self {
+        if let (Some(max), Some(min)) = (left.max(), right.min()) {
+            assert!(max <= min, "left treap overlaps right treap");
+        }
+
+        Self {
+            root: merge(left.root, right.root),
+            generator: left.generator,
+        }
+    }
+}
+
+impl<T> Default for Treap<T>
+where
+    T: PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T, G> IntoIterator for &'a Treap<T, G>
+where
+    T: PartialOrd,
+    G: PriorityGenerator,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// 隐式树堆
+///
+/// 以元素在序列中的位置作为键的树堆，节点不比较元素的值，而是根据子树大小定位元素，
+/// 可以作为按下标访问的序列使用。按下标访问、插入、删除以及拆分、合并序列的期望时间复杂度均为 O(logn)。
+pub struct ImplicitTreap<T, G = XorShift>
+where
+    G: PriorityGenerator,
+{
+    root: Link<T>,
+    generator: G,
+}
+
+impl<T> ImplicitTreap<T> {
+    /// 创建一个使用默认优先级生成器的空序列
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::with_generator(XorShift::default())
+    }
+}
+
+impl<T, G> ImplicitTreap<T, G>
+where
+    G: PriorityGenerator,
+{
+    /// 创建一个使用指定优先级生成器的空序列
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::{ImplicitTreap, XorShift};
+    /// let mut seq = ImplicitTreap::with_generator(XorShift::new(42));
+    /// seq.push(1);
+    /// ```
+    pub fn with_generator(generator: G) -> Self {
+        Self {
+            root: None,
+            generator,
+        }
+    }
+
+    /// 获取元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// seq.push(1);
+    ///
+    /// assert_eq!(seq.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// 判断序列是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let seq: ImplicitTreap<i32> = ImplicitTreap::new();
+    ///
+    /// assert!(seq.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 获取指定下标的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// seq.push(1);
+    ///
+    /// assert_eq!(seq.get(0), Some(&1));
+    /// assert_eq!(seq.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        select(&self.root, index).map(|node| &node.val)
+    }
+
+    /// 获取指定下标的元素的可变引用
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// seq.push(1);
+    /// *seq.get_mut(0).unwrap() = 2;
+    ///
+    /// assert_eq!(seq[0], 2);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        select_mut(&mut self.root, index).map(|node| &mut node.val)
+    }
+
+    /// 在序列末尾添加一个元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// seq.push(1);
+    /// seq.push(2);
+    ///
+    /// assert!(seq.iter().eq(&[1, 2]));
+    /// ```
+    pub fn push(&mut self, val: T) {
+        let node = Box::new(Node::new(val, self.generator.next_priority()));
+        self.root = merge(self.root.take(), Some(node));
+    }
+
+    /// 在指定下标处插入一个元素，之后的元素依次后移
+    ///
+    /// # Panics
+    ///
+    /// 下标大于序列长度时 panic。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// seq.push(1);
+    /// seq.push(3);
+    /// seq.insert(1, 2);
+    ///
+    /// assert!(seq.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn insert(&mut self, index: usize, val: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        let (left, right) = split_at(self.root.take(), index);
+        let node = Box::new(Node::new(val, self.generator.next_priority()));
+        self.root = merge(merge(left, Some(node)), right);
+    }
+
+    /// 删除并返回指定下标的元素，之后的元素依次前移
+    ///
+    /// # Panics
+    ///
+    /// 下标越界时 panic。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// for val in 1..=3 {
+    ///     seq.push(val);
+    /// }
+    ///
+    /// assert_eq!(seq.remove(1), 2);
+    /// assert!(seq.iter().eq(&[1, 3]));
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+
+        let (left, right) = split_at(self.root.take(), index);
+        let (node, right) = split_at(right, 1);
+        self.root = merge(left, right);
+        node.unwrap().val
+    }
+
+    /// 将序列在指定下标处拆分，返回下标及之后的元素，返回的序列使用 [`PriorityGenerator::fork`] 创建的生成器
+    ///
+    /// # Panics
+    ///
+    /// 下标大于序列长度时 panic。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// for val in 1..=4 {
+    ///     seq.push(val);
+    /// }
+    ///
+    /// let tail = seq.split_off(1);
+    /// assert!(seq.iter().eq(&[1]));
+    /// assert!(tail.iter().eq(&[2, 3, 4]));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        let (left, right) = split_at(self.root.take(), at);
+        self.root = left;
+        Self {
+            root: right,
+            generator: self.generator.fork(),
+        }
+    }
+
+    /// 将另一个序列的元素全部移动到当前序列的末尾，另一个序列变为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// let mut other = ImplicitTreap::new();
+    /// seq.push(1);
+    /// other.push(2);
+    ///
+    /// seq.append(&mut other);
+    /// assert!(seq.iter().eq(&[1, 2]));
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        self.root = merge(self.root.take(), other.root.take());
+    }
+
+    /// 获取按下标顺序迭代元素的迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::data_structures::ImplicitTreap;
+    /// let mut seq = ImplicitTreap::new();
+    /// seq.push(2);
+    /// seq.insert(0, 1);
+    ///
+    /// assert!(seq.iter().eq(&[1, 2]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.root)
+    }
+}
+
+impl<T> Default for ImplicitTreap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, G> Index<usize> for ImplicitTreap<T, G>
+where
+    G: PriorityGenerator,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, T, G> IntoIterator for &'a ImplicitTreap<T, G>
+where
+    G: PriorityGenerator,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// 中序遍历的迭代器
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn new(root: &'a Link<T>) -> Self {
+        let mut iter = Self {
+            stack: Vec::new(),
+            len: size(root),
+        };
+        iter.push_left(root);
+        iter
+    }
+
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.len -= 1;
+        Some(&node.val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::random_vec;
+    use alloc::vec::Vec;
+
+    /// 检查节点的优先级满足大顶堆的性质且子树大小正确
+    fn check_node<T>(link: &Link<T>) -> usize {
+        let Some(node) = link else {
+            return 0;
+        };
+        for child in [&node.left, &node.right].into_iter().flatten() {
+            assert!(child.priority <= node.priority);
+        }
+        let size = check_node(&node.left) + check_node(&node.right) + 1;
+        assert_eq!(node.size, size);
+        size
+    }
+
+    fn check<T, G>(treap: &Treap<T, G>)
+    where
+        T: PartialOrd,
+        G: PriorityGenerator,
+    {
+        check_node(&treap.root);
+        let values: Vec<&T> = treap.iter().collect();
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    fn prepare_treap() -> Treap<i32> {
+        let mut result = Treap::new();
+        for val in [1, 2, 3, 5, 6, 7] {
+            result.insert(val);
+        }
+        result
+    }
+
+    /// 按前序遍历记录树的形状
+    fn shape<T>(link: &Link<T>, result: &mut Vec<usize>) {
+        if let Some(node) = link {
+            result.push(node.size);
+            shape(&node.left, result);
+            shape(&node.right, result);
+        }
+    }
+
+    #[test]
+    fn queries() {
+        let treap = prepare_treap();
+        check(&treap);
+        assert!(treap.search(&1));
+        assert!(!treap.search(&4));
+        assert_eq!(treap.max(), Some(&7));
+        assert_eq!(treap.min(), Some(&1));
+        assert_eq!(treap.floor(&4), Some(&3));
+        assert_eq!(treap.ceil(&4), Some(&5));
+        assert_eq!(treap.rank(&5), 3);
+        assert_eq!(treap.select(3), Some(&5));
+        assert_eq!(treap.count_range(&2, &6), 4);
+        assert!(treap.range(3..=6).eq(&[3, 5, 6]));
+        assert!(treap
+            .range((Bound::Excluded(5), Bound::Excluded(3)))
+            .eq(&[] as &[i32]));
+    }
+
+    #[test]
+    fn delete() {
+        let mut treap = prepare_treap();
+        assert_eq!(treap.pop_min(), Some(1));
+        assert_eq!(treap.pop_max(), Some(7));
+        assert_eq!(treap.remove(&4), None);
+        assert_eq!(treap.remove(&3), Some(3));
+        check(&treap);
+        assert!(treap.iter().eq(&[2, 5, 6]));
+    }
+
+    #[test]
+    fn random() {
+        let mut treap = Treap::new();
+        let mut expected = Vec::new();
+        let ops = random_vec(3000, 54);
+        for (idx, val) in random_vec(3000, 55).into_iter().enumerate() {
+            let val = val % 300;
+            match ops[idx] % 4 {
+                0 => {
+                    let pos = expected.iter().position(|it| *it == val);
+                    assert_eq!(treap.remove(&val), pos.map(|pos| expected.remove(pos)));
+                }
+                1 => assert_eq!(treap.pop_max(), expected.pop()),
+                _ => {
+                    treap.insert(val);
+                    expected.push(val);
+                    expected.sort();
+                }
+            }
+        }
+        check(&treap);
+        assert!(treap.iter().eq(expected.iter()));
+        assert_eq!(treap.rank(&150), expected.partition_point(|it| *it < 150));
+    }
+
+    /// 两个生成器接下来生成的优先级各不相同
+    fn distinct_generators(a: &XorShift, b: &XorShift) -> bool {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        (0..100).all(|_| a.next_priority() != b.next_priority())
+    }
+
+    #[test]
+    fn split_and_merge() {
+        let mut treap = Treap::new();
+        for val in random_vec(1000, 56) {
+            treap.insert(val);
+        }
+
+        let (left, right) = treap.split(&500);
+        check(&left);
+        check(&right);
+        assert!(left.iter().all(|it| *it < 500));
+        assert!(right.iter().all(|it| *it >= 500));
+        assert_eq!(left.len() + right.len(), 1000);
+        assert!(distinct_generators(&left.generator, &right.generator));
+
+        let treap = Treap::merge(left, right);
+        check(&treap);
+        let mut expected = random_vec(1000, 56);
+        expected.sort();
+        assert!(treap.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn merge_overlapping() {
+        let left = prepare_treap();
+        let right = prepare_treap();
+        Treap::merge(left, right);
+    }
+
+    /// 优先级递增的生成器，树堆退化为链表
+    struct Increasing(u64);
+
+    impl PriorityGenerator for Increasing {
+        fn next_priority(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+
+        fn fork(&mut self) -> Self {
+            Increasing(self.0)
+        }
+    }
+
+    #[test]
+    fn generator() {
+        let mut treap = Treap::with_generator(Increasing(0));
+        for val in 0..100 {
+            treap.insert(val);
+        }
+        check(&treap);
+        // 最后插入的元素优先级最大，成为根节点
+        assert_eq!(treap.root.as_ref().unwrap().val, 99);
+
+        let mut a = Treap::with_generator(XorShift::new(7));
+        let mut b = Treap::with_generator(XorShift::new(7));
+        for val in random_vec(100, 57) {
+            a.insert(val);
+            b.insert(val);
+        }
+        let (mut shape_a, mut shape_b) = (Vec::new(), Vec::new());
+        shape(&a.root, &mut shape_a);
+        shape(&b.root, &mut shape_b);
+        assert_eq!(shape_a, shape_b);
+    }
+
+    #[test]
+    fn implicit() {
+        let mut seq = ImplicitTreap::new();
+        let mut expected = Vec::new();
+        let ops = random_vec(2000, 58);
+        for (idx, val) in random_vec(2000, 59).into_iter().enumerate() {
+            let index = (val as usize) % (expected.len() + 1);
+            match ops[idx] % 3 {
+                0 if index < expected.len() => {
+                    assert_eq!(seq.remove(index), expected.remove(index));
+                }
+                _ => {
+                    seq.insert(index, val);
+                    expected.insert(index, val);
+                }
+            }
+        }
+        check_node(&seq.root);
+        assert!(seq.iter().eq(expected.iter()));
+        assert_eq!(seq[expected.len() / 2], expected[expected.len() / 2]);
+
+        let mut tail = seq.split_off(100);
+        assert!(seq.iter().eq(expected[..100].iter()));
+        assert!(tail.iter().eq(expected[100..].iter()));
+        assert!(distinct_generators(&seq.generator, &tail.generator));
+        tail.append(&mut seq);
+        assert_eq!(tail.len(), expected.len());
+        assert_eq!(tail.get(expected.len() - 100), Some(&expected[0]));
+    }
+}
@@ -1,3 +1,6 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 pub struct UnionFind {
     parent: Vec<usize>,
     size: Vec<usize>,
@@ -93,8 +96,8 @@ mod tests {
         assert!(uf.union(6, 7));
         assert!(uf.union(7, 8));
         assert!(uf.union(8, 9));
-        assert_eq!(uf.union(9, 0), false);
+        assert!(!uf.union(9, 0));
 
         assert_eq!(1, uf.count());
     }
-}
\ No newline at end of file
+}
@@ -1,2 +1,18 @@
+//! Rust 学习中的一些 demo
+//!
+//! 默认启用 `std` feature。关闭后 crate 以 `#![no_std]` 构建，依赖线程的 `parallel_sample_sort` 不可用：
+//!
+//! - 只启用 `alloc` feature 时，数据结构、归并排序、集合运算等需要分配内存的部分只依赖 `alloc`；
+//! - 不启用任何 feature 时只保留原地排序和不分配内存的查找，只依赖 `core`，不需要全局分配器。
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
 pub mod data_structures;
+pub mod search;
+#[cfg(feature = "alloc")]
+pub mod set;
 pub mod sort;
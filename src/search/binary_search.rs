@@ -0,0 +1,317 @@
+use core::cmp::Ordering;
+use core::cmp::Ordering::{Equal, Greater, Less};
+use core::ops::Range;
+
+/// 查找有序数组中第一个不小于指定值的元素位置
+///
+/// 所有元素都小于指定值时返回数组长度，时间复杂度为 O(logn)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::lower_bound;
+/// let arr = [1, 2, 2, 3];
+/// assert_eq!(lower_bound(&arr, &2), 1);
+/// assert_eq!(lower_bound(&arr, &4), 4);
+/// ```
+pub fn lower_bound<T>(arr: &[T], val: &T) -> usize
+where
+    T: Ord,
+{
+    lower_bound_by(arr, |it| it.cmp(val))
+}
+
+/// 使用比较函数查找第一个不小于目标的元素位置，比较函数返回元素相对于目标的大小
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::lower_bound_by;
+/// let arr = [3, 2, 2, 1];
+/// assert_eq!(lower_bound_by(&arr, |it| 2.cmp(it)), 1);
+/// ```
+pub fn lower_bound_by<T, F>(arr: &[T], mut f: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut low = 0;
+    let mut high = arr.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if f(&arr[mid]) == Less {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// 使用键提取函数查找第一个键不小于指定键的元素位置
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::lower_bound_by_key;
+/// let arr = [(1, 'a'), (2, 'b'), (2, 'c')];
+/// assert_eq!(lower_bound_by_key(&arr, &2, |it| it.0), 1);
+/// ```
+pub fn lower_bound_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    lower_bound_by(arr, |it| f(it).cmp(key))
+}
+
+/// 查找有序数组中第一个大于指定值的元素位置
+///
+/// 不存在大于指定值的元素时返回数组长度，时间复杂度为 O(logn)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::upper_bound;
+/// let arr = [1, 2, 2, 3];
+/// assert_eq!(upper_bound(&arr, &2), 3);
+/// assert_eq!(upper_bound(&arr, &0), 0);
+/// ```
+pub fn upper_bound<T>(arr: &[T], val: &T) -> usize
+where
+    T: Ord,
+{
+    upper_bound_by(arr, |it| it.cmp(val))
+}
+
+/// 使用比较函数查找第一个大于目标的元素位置，比较函数返回元素相对于目标的大小
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::upper_bound_by;
+/// let arr = [3, 2, 2, 1];
+/// assert_eq!(upper_bound_by(&arr, |it| 2.cmp(it)), 3);
+/// ```
+pub fn upper_bound_by<T, F>(arr: &[T], mut f: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut low = 0;
+    let mut high = arr.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if f(&arr[mid]) == Greater {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+/// 使用键提取函数查找第一个键大于指定键的元素位置
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::upper_bound_by_key;
+/// let arr = [(1, 'a'), (2, 'b'), (2, 'c')];
+/// assert_eq!(upper_bound_by_key(&arr, &2, |it| it.0), 3);
+/// ```
+pub fn upper_bound_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    upper_bound_by(arr, |it| f(it).cmp(key))
+}
+
+/// 查找有序数组中所有等于指定值的元素范围
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::equal_range;
+/// let arr = [1, 2, 2, 3];
+/// assert_eq!(equal_range(&arr, &2), 1..3);
+/// assert_eq!(equal_range(&arr, &4), 4..4);
+/// ```
+pub fn equal_range<T>(arr: &[T], val: &T) -> Range<usize>
+where
+    T: Ord,
+{
+    equal_range_by(arr, |it| it.cmp(val))
+}
+
+/// 使用比较函数查找所有等于目标的元素范围，比较函数返回元素相对于目标的大小
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::equal_range_by;
+/// let arr = [3, 2, 2, 1];
+/// assert_eq!(equal_range_by(&arr, |it| 2.cmp(it)), 1..3);
+/// ```
+pub fn equal_range_by<T, F>(arr: &[T], mut f: F) -> Range<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let start = lower_bound_by(arr, &mut f);
+    let end = start + upper_bound_by(&arr[start..], &mut f);
+    start..end
+}
+
+/// 使用键提取函数查找所有键等于指定键的元素范围
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::equal_range_by_key;
+/// let arr = [(1, 'a'), (2, 'b'), (2, 'c')];
+/// assert_eq!(equal_range_by_key(&arr, &2, |it| it.0), 1..3);
+/// ```
+pub fn equal_range_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Range<usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    equal_range_by(arr, |it| f(it).cmp(key))
+}
+
+/// 无分支二分查找
+///
+/// 每次迭代只根据比较结果选择下一个区间的起点，循环次数只与数组长度有关，
+/// 编译器可生成条件传送指令，避免随机查询时的分支预测失败。
+/// 找到时返回第一个等于指定值的元素位置，否则返回可插入的位置，时间复杂度为 O(logn)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::branchless_search;
+/// let arr = [1, 2, 2, 3];
+/// assert_eq!(branchless_search(&arr, &2), Ok(1));
+/// assert_eq!(branchless_search(&arr, &4), Err(4));
+/// ```
+pub fn branchless_search<T>(arr: &[T], val: &T) -> Result<usize, usize>
+where
+    T: Ord,
+{
+    branchless_search_by(arr, |it| it.cmp(val))
+}
+
+/// 使用比较函数的无分支二分查找，比较函数返回元素相对于目标的大小
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::branchless_search_by;
+/// let arr = [3, 2, 2, 1];
+/// assert_eq!(branchless_search_by(&arr, |it| 2.cmp(it)), Ok(1));
+/// ```
+pub fn branchless_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    if arr.is_empty() {
+        return Err(0);
+    }
+
+    let mut base = 0;
+    let mut size = arr.len();
+    while size > 1 {
+        let half = size / 2;
+        let mid = base + half;
+        base = if f(&arr[mid]) == Less { mid } else { base };
+        size -= half;
+    }
+
+    let idx = base + (f(&arr[base]) == Less) as usize;
+    match arr.get(idx) {
+        Some(it) if f(it) == Equal => Ok(idx),
+        _ => Err(idx),
+    }
+}
+
+/// 使用键提取函数的无分支二分查找
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::branchless_search_by_key;
+/// let arr = [(1, 'a'), (2, 'b'), (2, 'c')];
+/// assert_eq!(branchless_search_by_key(&arr, &2, |it| it.0), Ok(1));
+/// ```
+pub fn branchless_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    branchless_search_by(arr, |it| f(it).cmp(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::{heap_sort, quick_sort, random_vec};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let arr: Vec<i32> = vec![];
+        assert_eq!(lower_bound(&arr, &1), 0);
+        assert_eq!(upper_bound(&arr, &1), 0);
+        assert_eq!(equal_range(&arr, &1), 0..0);
+        assert_eq!(branchless_search(&arr, &1), Err(0));
+    }
+
+    #[test]
+    fn bounds() {
+        let mut arr = vec![3, 1, 2, 2, 5, 2];
+        quick_sort(&mut arr);
+
+        assert_eq!(lower_bound(&arr, &0), 0);
+        assert_eq!(lower_bound(&arr, &2), 1);
+        assert_eq!(lower_bound(&arr, &4), 5);
+        assert_eq!(lower_bound(&arr, &6), 6);
+
+        assert_eq!(upper_bound(&arr, &0), 0);
+        assert_eq!(upper_bound(&arr, &2), 4);
+        assert_eq!(upper_bound(&arr, &5), 6);
+
+        assert_eq!(equal_range(&arr, &2), 1..4);
+        assert_eq!(equal_range(&arr, &4), 5..5);
+
+        assert_eq!(branchless_search(&arr, &2), Ok(1));
+        assert_eq!(branchless_search(&arr, &4), Err(5));
+    }
+
+    #[test]
+    fn by_key() {
+        let arr = [(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd')];
+        assert_eq!(lower_bound_by_key(&arr, &2, |it| it.0), 1);
+        assert_eq!(upper_bound_by_key(&arr, &2, |it| it.0), 3);
+        assert_eq!(equal_range_by_key(&arr, &3, |it| it.0), 3..3);
+        assert_eq!(branchless_search_by_key(&arr, &4, |it| it.0), Ok(3));
+        assert_eq!(branchless_search_by_key(&arr, &3, |it| it.0), Err(3));
+    }
+
+    #[test]
+    fn random() {
+        for len in [1, 2, 3, 100, 1000] {
+            let mut arr = random_vec(len, len as u64);
+            heap_sort(&mut arr);
+
+            for val in 0..1001 {
+                let lower = arr.iter().filter(|it| **it < val).count();
+                let upper = arr.iter().filter(|it| **it <= val).count();
+                assert_eq!(lower_bound(&arr, &val), lower);
+                assert_eq!(upper_bound(&arr, &val), upper);
+                assert_eq!(equal_range(&arr, &val), lower..upper);
+
+                let expected = if lower < upper { Ok(lower) } else { Err(lower) };
+                assert_eq!(branchless_search(&arr, &val), expected);
+            }
+        }
+    }
+}
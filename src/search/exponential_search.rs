@@ -0,0 +1,113 @@
+use core::cmp::Ordering;
+use core::cmp::Ordering::{Equal, Less};
+
+use super::lower_bound_by;
+
+/// 指数查找（倍增查找）
+///
+/// 从数组头部开始以 1、2、4、8…… 的步长确定目标所在的区间，再在区间内二分查找。
+/// 目标位于数组前部时速度很快，时间复杂度为 O(logi)，i 为目标所在的位置。
+/// 找到时返回第一个等于指定值的元素位置，否则返回可插入的位置。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::exponential_search;
+/// let arr = [1, 2, 2, 3];
+/// assert_eq!(exponential_search(&arr, &2), Ok(1));
+/// assert_eq!(exponential_search(&arr, &0), Err(0));
+/// ```
+pub fn exponential_search<T>(arr: &[T], val: &T) -> Result<usize, usize>
+where
+    T: Ord,
+{
+    exponential_search_by(arr, |it| it.cmp(val))
+}
+
+/// 使用比较函数的指数查找，比较函数返回元素相对于目标的大小
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::exponential_search_by;
+/// let arr = [3, 2, 2, 1];
+/// assert_eq!(exponential_search_by(&arr, |it| 2.cmp(it)), Ok(1));
+/// ```
+pub fn exponential_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut bound = 1;
+    while bound < arr.len() && f(&arr[bound]) == Less {
+        bound *= 2;
+    }
+
+    // arr[bound / 2] 小于目标（bound 为 1 时除外），arr[bound] 不小于目标
+    let start = bound / 2;
+    let end = (bound + 1).min(arr.len());
+    let idx = start + lower_bound_by(&arr[start..end], &mut f);
+    match arr.get(idx) {
+        Some(it) if f(it) == Equal => Ok(idx),
+        _ => Err(idx),
+    }
+}
+
+/// 使用键提取函数的指数查找
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::exponential_search_by_key;
+/// let arr = [(1, 'a'), (2, 'b'), (2, 'c')];
+/// assert_eq!(exponential_search_by_key(&arr, &2, |it| it.0), Ok(1));
+/// ```
+pub fn exponential_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    exponential_search_by(arr, |it| f(it).cmp(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::{heap_sort, random_vec};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let arr: Vec<i32> = vec![];
+        assert_eq!(exponential_search(&arr, &1), Err(0));
+    }
+
+    #[test]
+    fn search() {
+        let arr = [1, 2, 2, 3, 5, 8, 13];
+        assert_eq!(exponential_search(&arr, &1), Ok(0));
+        assert_eq!(exponential_search(&arr, &2), Ok(1));
+        assert_eq!(exponential_search(&arr, &4), Err(4));
+        assert_eq!(exponential_search(&arr, &13), Ok(6));
+        assert_eq!(exponential_search(&arr, &14), Err(7));
+        assert_eq!(exponential_search_by_key(&arr, &6, |it| it * 2), Ok(3));
+    }
+
+    #[test]
+    fn random() {
+        for len in [1, 2, 3, 100, 1000] {
+            let mut arr = random_vec(len, len as u64);
+            heap_sort(&mut arr);
+
+            for val in 0..1001 {
+                let lower = arr.iter().filter(|it| **it < val).count();
+                let expected = if arr.get(lower) == Some(&val) {
+                    Ok(lower)
+                } else {
+                    Err(lower)
+                };
+                assert_eq!(exponential_search(&arr, &val), expected);
+            }
+        }
+    }
+}
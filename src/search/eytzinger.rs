@@ -0,0 +1,188 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::cmp::Ordering::{Equal, Less};
+
+/// Eytzinger 布局的有序数组
+///
+/// 按完全二叉搜索树的层序存储元素，下标为 k 的节点的子节点位于 2k 和 2k + 1（下标从 1 开始），
+/// 查找路径上的元素在内存中集中于数组前部，缓存命中率远高于普通有序数组上的二分查找，
+/// 且子节点的位置可提前预取。
+pub struct Eytzinger<T> {
+    items: Vec<T>,
+}
+
+impl<T> Eytzinger<T> {
+    /// 从有序数组构建 Eytzinger 布局，时间复杂度为 O(n)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::search::Eytzinger;
+    /// let tree = Eytzinger::from_sorted(vec![1, 2, 3]);
+    /// ```
+    pub fn from_sorted(sorted: Vec<T>) -> Self {
+        let mut slots: Vec<Option<T>> = sorted.iter().map(|_| None).collect();
+        let mut iter = sorted.into_iter();
+        fill(&mut slots, 1, &mut iter);
+        Self {
+            items: slots.into_iter().map(|it| it.unwrap()).collect(),
+        }
+    }
+
+    /// 返回元素数量
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::search::Eytzinger;
+    /// let tree = Eytzinger::from_sorted(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// 返回是否为空
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::search::Eytzinger;
+    /// let tree: Eytzinger<i32> = Eytzinger::from_sorted(vec![]);
+    ///
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 使用比较函数查找第一个不小于目标的元素，比较函数返回元素相对于目标的大小
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::search::Eytzinger;
+    /// let tree = Eytzinger::from_sorted(vec![1, 3, 5]);
+    ///
+    /// assert_eq!(tree.lower_bound_by(|it| it.cmp(&2)), Some(&3));
+    /// ```
+    pub fn lower_bound_by<F>(&self, mut f: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        // 元素小于目标时向右，否则向左，k 的二进制位记录了查找路径
+        let mut k = 1;
+        while k <= self.items.len() {
+            k = 2 * k + (f(&self.items[k - 1]) == Less) as usize;
+        }
+        // 去掉末尾连续向右的路径，得到最后一次向左的节点
+        k >>= k.trailing_ones() + 1;
+        if k == 0 {
+            None
+        } else {
+            Some(&self.items[k - 1])
+        }
+    }
+
+    /// 使用键提取函数查找第一个键不小于指定键的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::search::Eytzinger;
+    /// let tree = Eytzinger::from_sorted(vec![(1, 'a'), (3, 'b')]);
+    ///
+    /// assert_eq!(tree.lower_bound_by_key(&2, |it| it.0), Some(&(3, 'b')));
+    /// ```
+    pub fn lower_bound_by_key<K, F>(&self, key: &K, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.lower_bound_by(|it| f(it).cmp(key))
+    }
+}
+
+impl<T> Eytzinger<T>
+where
+    T: Ord,
+{
+    /// 查找第一个不小于指定值的元素
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::search::Eytzinger;
+    /// let tree = Eytzinger::from_sorted(vec![1, 3, 5]);
+    ///
+    /// assert_eq!(tree.lower_bound(&3), Some(&3));
+    /// assert_eq!(tree.lower_bound(&4), Some(&5));
+    /// assert_eq!(tree.lower_bound(&6), None);
+    /// ```
+    pub fn lower_bound(&self, val: &T) -> Option<&T> {
+        self.lower_bound_by(|it| it.cmp(val))
+    }
+
+    /// 查询是否存在指定值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::search::Eytzinger;
+    /// let tree = Eytzinger::from_sorted(vec![1, 3, 5]);
+    ///
+    /// assert!(tree.contains(&3));
+    /// assert!(!tree.contains(&4));
+    /// ```
+    pub fn contains(&self, val: &T) -> bool {
+        self.lower_bound(val).map(|it| it.cmp(val)) == Some(Equal)
+    }
+}
+
+/// 按中序遍历的顺序将有序元素依次放入以 k 为根的子树
+fn fill<T>(slots: &mut [Option<T>], k: usize, iter: &mut impl Iterator<Item = T>) {
+    if k > slots.len() {
+        return;
+    }
+    fill(slots, 2 * k, iter);
+    slots[k - 1] = iter.next();
+    fill(slots, 2 * k + 1, iter);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::{bu_merge_sort, random_vec};
+    use alloc::vec;
+
+    #[test]
+    fn empty() {
+        let tree: Eytzinger<i32> = Eytzinger::from_sorted(vec![]);
+        assert!(tree.is_empty());
+        assert_eq!(tree.lower_bound(&1), None);
+        assert!(!tree.contains(&1));
+    }
+
+    #[test]
+    fn layout() {
+        let tree = Eytzinger::from_sorted(vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(tree.items, [4, 2, 6, 1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn random() {
+        for len in [1, 2, 3, 100, 1000] {
+            let mut arr = random_vec(len, len as u64);
+            bu_merge_sort(&mut arr);
+            let tree = Eytzinger::from_sorted(arr.clone());
+            assert_eq!(tree.len(), arr.len());
+
+            for val in 0..1001 {
+                let expected = arr.iter().find(|it| **it >= val);
+                assert_eq!(tree.lower_bound(&val), expected);
+                assert_eq!(tree.contains(&val), arr.contains(&val));
+            }
+        }
+    }
+}
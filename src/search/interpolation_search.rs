@@ -0,0 +1,183 @@
+use core::cmp::Ordering;
+use core::cmp::Ordering::{Equal, Greater, Less};
+
+/// 可用于插值查找的数值类型
+pub trait Numeric: Copy + PartialOrd {
+    /// 转换为 f64，用于估算目标所在的位置
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// 插值查找
+///
+/// 根据目标值在首尾元素之间的比例估算其位置，而不是每次取中点。
+/// 元素分布均匀时平均时间复杂度为 O(loglogn)，最坏情况下为 O(n)。
+/// 找到时返回任意一个等于指定值的元素位置，否则返回可插入的位置。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::interpolation_search;
+/// let arr = [10, 20, 30, 40, 50];
+/// assert_eq!(interpolation_search(&arr, &40), Ok(3));
+/// assert_eq!(interpolation_search(&arr, &35), Err(3));
+/// ```
+pub fn interpolation_search<T>(arr: &[T], val: &T) -> Result<usize, usize>
+where
+    T: Numeric,
+{
+    interpolation_search_by_key(arr, val, |it| *it)
+}
+
+/// 使用距离函数的插值查找
+///
+/// 距离函数返回元素与目标的差值：元素小于目标时为负数，大于目标时为正数，等于目标时为 0。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::interpolation_search_by;
+/// let arr = [1.5, 2.5, 3.5];
+/// assert_eq!(interpolation_search_by(&arr, |it| it - 2.5), Ok(1));
+/// ```
+pub fn interpolation_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> f64,
+{
+    interpolation(
+        arr,
+        |it| {
+            let distance = f(it);
+            (distance.partial_cmp(&0.0).unwrap_or(Equal), distance)
+        },
+        0.0,
+    )
+}
+
+/// 使用键提取函数的插值查找
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::search::interpolation_search_by_key;
+/// let arr = [(10, 'a'), (20, 'b'), (30, 'c')];
+/// assert_eq!(interpolation_search_by_key(&arr, &20, |it| it.0), Ok(1));
+/// ```
+pub fn interpolation_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+where
+    K: Numeric,
+    F: FnMut(&T) -> K,
+{
+    // 比较时直接使用原始类型，避免大整数转换为 f64 时损失精度
+    interpolation(
+        arr,
+        |it| {
+            let it = f(it);
+            (it.partial_cmp(key).unwrap_or(Equal), it.to_f64())
+        },
+        key.to_f64(),
+    )
+}
+
+/// 插值查找的实现，f 返回元素与目标的比较结果以及元素用于估算位置的数值
+fn interpolation<T, F>(arr: &[T], mut f: F, target: f64) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> (Ordering, f64),
+{
+    let mut low = 0;
+    let mut high = arr.len();
+    while low < high {
+        let (low_cmp, low_pos) = f(&arr[low]);
+        if low_cmp == Greater {
+            return Err(low);
+        }
+        let (high_cmp, high_pos) = f(&arr[high - 1]);
+        if high_cmp == Less {
+            return Err(high);
+        }
+
+        // 此时 arr[low] <= 目标 <= arr[high - 1]
+        let mid = if high_pos > low_pos {
+            let ratio = (target - low_pos) / (high_pos - low_pos);
+            low + ((ratio * (high - 1 - low) as f64) as usize).min(high - 1 - low)
+        } else {
+            low
+        };
+
+        match f(&arr[mid]).0 {
+            Less => low = mid + 1,
+            Greater => high = mid,
+            Equal => return Ok(mid),
+        }
+    }
+    Err(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::{random_vec, shell_sort};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let arr: Vec<i32> = vec![];
+        assert_eq!(interpolation_search(&arr, &1), Err(0));
+    }
+
+    #[test]
+    fn search() {
+        let arr = [1, 2, 2, 3, 5, 8, 13];
+        assert_eq!(interpolation_search(&arr, &0), Err(0));
+        assert_eq!(interpolation_search(&arr, &1), Ok(0));
+        assert_eq!(interpolation_search(&arr, &4), Err(4));
+        assert_eq!(interpolation_search(&arr, &13), Ok(6));
+        assert_eq!(interpolation_search(&arr, &14), Err(7));
+        assert!(matches!(interpolation_search(&arr, &2), Ok(1 | 2)));
+    }
+
+    #[test]
+    fn large_integers() {
+        let arr = [u64::MAX - 2, u64::MAX - 1, u64::MAX];
+        assert_eq!(interpolation_search(&arr, &(u64::MAX - 1)), Ok(1));
+    }
+
+    #[test]
+    fn floats() {
+        let arr = [-1.5, 0.0, 0.25, 7.0];
+        assert_eq!(interpolation_search(&arr, &0.25), Ok(2));
+        assert_eq!(interpolation_search(&arr, &1.0), Err(3));
+        assert_eq!(interpolation_search_by(&arr, |it| it - 7.0), Ok(3));
+    }
+
+    #[test]
+    fn random() {
+        for len in [1, 2, 3, 100, 1000] {
+            let mut arr = random_vec(len, len as u64);
+            shell_sort(&mut arr);
+
+            for val in 0..1001 {
+                let lower = arr.iter().filter(|it| **it < val).count();
+                let upper = arr.iter().filter(|it| **it <= val).count();
+                match interpolation_search(&arr, &val) {
+                    Ok(idx) => assert!(lower <= idx && idx < upper),
+                    Err(idx) => assert!(lower == upper && idx == lower),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub use self::binary_search::{
+    branchless_search, branchless_search_by, branchless_search_by_key, equal_range, equal_range_by,
+    equal_range_by_key, lower_bound, lower_bound_by, lower_bound_by_key, upper_bound,
+    upper_bound_by, upper_bound_by_key,
+};
+pub use self::exponential_search::{
+    exponential_search, exponential_search_by, exponential_search_by_key,
+};
+#[cfg(feature = "alloc")]
+pub use self::eytzinger::Eytzinger;
+pub use self::interpolation_search::{
+    interpolation_search, interpolation_search_by, interpolation_search_by_key, Numeric,
+};
+
+mod binary_search;
+mod exponential_search;
+#[cfg(feature = "alloc")]
+mod eytzinger;
+mod interpolation_search;
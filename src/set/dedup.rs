@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// 去除有序数组中的重复元素，返回每个值只出现一次的新数组
+///
+/// 时间复杂度为 O(n)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::set::dedup_sorted;
+/// assert_eq!(dedup_sorted(&[1, 1, 2, 3, 3, 3]), [1, 2, 3]);
+/// ```
+pub fn dedup_sorted<T>(arr: &[T]) -> Vec<T>
+where
+    T: PartialEq + Clone,
+{
+    dedup_sorted_iter(arr).cloned().collect()
+}
+
+/// 去除有序数组中重复元素的惰性迭代器，每组相等元素只返回第一个
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::set::dedup_sorted_iter;
+/// let mut iter = dedup_sorted_iter(&[1, 1, 2]);
+/// assert_eq!(iter.next(), Some(&1));
+/// assert_eq!(iter.next(), Some(&2));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn dedup_sorted_iter<T>(arr: &[T]) -> Dedup<'_, T>
+where
+    T: PartialEq,
+{
+    Dedup { rest: arr }
+}
+
+/// [`dedup_sorted_iter`] 返回的迭代器，每组相等元素只返回第一个
+pub struct Dedup<'a, T> {
+    rest: &'a [T],
+}
+
+impl<'a, T> Iterator for Dedup<'a, T>
+where
+    T: PartialEq,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.rest.first()?;
+        let run = self.rest.iter().take_while(|it| *it == first).count();
+        self.rest = &self.rest[run..];
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rest.len().min(1), Some(self.rest.len()))
+    }
+}
+
+impl<T> FusedIterator for Dedup<'_, T> where T: PartialEq {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::{random_vec, shell_sort};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let arr: Vec<i32> = vec![];
+        assert!(dedup_sorted(&arr).is_empty());
+        assert_eq!(dedup_sorted_iter(&arr).next(), None);
+    }
+
+    #[test]
+    fn dedup() {
+        assert_eq!(dedup_sorted(&[1]), [1]);
+        assert_eq!(dedup_sorted(&[1, 1, 1]), [1]);
+        assert_eq!(dedup_sorted(&["a", "a", "b", "c", "c"]), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn random() {
+        let mut arr = random_vec(1000, 7);
+        shell_sort(&mut arr);
+        let mut expected = arr.clone();
+        expected.dedup();
+        assert_eq!(dedup_sorted(&arr), expected);
+    }
+}
@@ -0,0 +1,12 @@
+pub use self::dedup::{dedup_sorted, dedup_sorted_iter, Dedup};
+pub use self::sorted_set::{
+    sorted_difference, sorted_difference_iter, sorted_intersection, sorted_intersection_iter,
+    sorted_multiset_difference, sorted_multiset_difference_iter, sorted_multiset_intersection,
+    sorted_multiset_intersection_iter, sorted_multiset_symmetric_difference,
+    sorted_multiset_symmetric_difference_iter, sorted_multiset_union, sorted_multiset_union_iter,
+    sorted_symmetric_difference, sorted_symmetric_difference_iter, sorted_union, sorted_union_iter,
+    SortedSetIter,
+};
+
+mod dedup;
+mod sorted_set;
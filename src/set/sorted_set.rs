@@ -0,0 +1,516 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering::{Equal, Greater, Less};
+use core::iter::FusedIterator;
+
+use crate::search::exponential_search;
+
+/// 两个有序数组的长度之比不小于该值时，求交集使用指数查找跳过较长数组中的元素
+const GALLOP_RATIO: usize = 16;
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// 有序数组集合运算的惰性迭代器
+///
+/// 同时遍历两个有序数组，每次取出较小的值及其在两个数组中的出现次数，
+/// 再根据集合运算的类型决定输出该值的次数，元素引用来自第一个包含该值的数组。
+pub struct SortedSetIter<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+    operation: Operation,
+    multiset: bool,
+    gallop: bool,
+    pending: Option<&'a T>,
+    remaining: usize,
+}
+
+impl<'a, T> SortedSetIter<'a, T>
+where
+    T: Ord,
+{
+    fn new(a: &'a [T], b: &'a [T], operation: Operation, multiset: bool) -> Self {
+        let (short, long) = if a.len() < b.len() {
+            (a.len(), b.len())
+        } else {
+            (b.len(), a.len())
+        };
+        Self {
+            a,
+            b,
+            operation,
+            multiset,
+            gallop: long / short.max(1) >= GALLOP_RATIO,
+            pending: None,
+            remaining: 0,
+        }
+    }
+
+    /// 求交集时跳过只在一侧出现的元素，直到两侧的首个元素相等
+    fn align(&mut self) {
+        while let (Some(x), Some(y)) = (self.a.first(), self.b.first()) {
+            match x.cmp(y) {
+                Less => self.a = &self.a[skip(self.a, y, self.gallop)..],
+                Greater => self.b = &self.b[skip(self.b, x, self.gallop)..],
+                Equal => break,
+            }
+        }
+    }
+
+    /// 取出较小的值及其在两个数组中的连续出现次数
+    fn next_run(&mut self) -> Option<(&'a T, usize, usize)> {
+        let (val, count_a, count_b) = match (self.a.first(), self.b.first()) {
+            (None, None) => return None,
+            (Some(x), None) => (x, run_len(self.a), 0),
+            (None, Some(y)) => (y, 0, run_len(self.b)),
+            (Some(x), Some(y)) => match x.cmp(y) {
+                Less => (x, run_len(self.a), 0),
+                Greater => (y, 0, run_len(self.b)),
+                Equal => (x, run_len(self.a), run_len(self.b)),
+            },
+        };
+        self.a = &self.a[count_a..];
+        self.b = &self.b[count_b..];
+        Some((val, count_a, count_b))
+    }
+
+    /// 值在两个数组中分别出现 count_a 和 count_b 次时，在结果中出现的次数
+    fn output_count(&self, count_a: usize, count_b: usize) -> usize {
+        if self.multiset {
+            match self.operation {
+                Operation::Union => count_a.max(count_b),
+                Operation::Intersection => count_a.min(count_b),
+                Operation::Difference => count_a.saturating_sub(count_b),
+                Operation::SymmetricDifference => count_a.abs_diff(count_b),
+            }
+        } else {
+            let (in_a, in_b) = (count_a > 0, count_b > 0);
+            let present = match self.operation {
+                Operation::Union => in_a || in_b,
+                Operation::Intersection => in_a && in_b,
+                Operation::Difference => in_a && !in_b,
+                Operation::SymmetricDifference => in_a != in_b,
+            };
+            present as usize
+        }
+    }
+}
+
+impl<'a, T> Iterator for SortedSetIter<'a, T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining > 0 {
+                self.remaining -= 1;
+                return self.pending;
+            }
+
+            match self.operation {
+                Operation::Intersection => {
+                    self.align();
+                    if self.a.is_empty() || self.b.is_empty() {
+                        return None;
+                    }
+                }
+                Operation::Difference if self.a.is_empty() => return None,
+                _ => {}
+            }
+
+            let (val, count_a, count_b) = self.next_run()?;
+            self.pending = Some(val);
+            self.remaining = self.output_count(count_a, count_b);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.a.len(), self.b.len());
+        let upper = match self.operation {
+            Operation::Union | Operation::SymmetricDifference => a + b,
+            Operation::Intersection => a.min(b),
+            Operation::Difference => a,
+        };
+        (self.remaining, Some(self.remaining + upper))
+    }
+}
+
+impl<T> FusedIterator for SortedSetIter<'_, T> where T: Ord {}
+
+/// 有序数组开头与首个元素相等的元素个数
+fn run_len<T>(arr: &[T]) -> usize
+where
+    T: Ord,
+{
+    arr.iter().take_while(|it| **it == arr[0]).count()
+}
+
+/// 有序数组开头小于指定值的元素个数，gallop 为 true 时使用指数查找，否则只跳过一个元素
+fn skip<T>(arr: &[T], val: &T, gallop: bool) -> usize
+where
+    T: Ord,
+{
+    if !gallop {
+        return 1;
+    }
+    match exponential_search(arr, val) {
+        Ok(idx) | Err(idx) => idx,
+    }
+}
+
+macro_rules! sorted_set_operation {
+    ($(#[$vec_doc:meta])* $vec_fn:ident, $(#[$iter_doc:meta])* $iter_fn:ident, $operation:ident, $multiset:expr) => {
+        $(#[$vec_doc])*
+        pub fn $vec_fn<T>(a: &[T], b: &[T]) -> Vec<T>
+        where
+            T: Ord + Clone,
+        {
+            $iter_fn(a, b).cloned().collect()
+        }
+
+        $(#[$iter_doc])*
+        pub fn $iter_fn<'a, T>(a: &'a [T], b: &'a [T]) -> SortedSetIter<'a, T>
+        where
+            T: Ord,
+        {
+            SortedSetIter::new(a, b, Operation::$operation, $multiset)
+        }
+    };
+}
+
+sorted_set_operation!(
+    /// 求两个有序数组的并集，结果中每个值只出现一次
+    ///
+    /// 时间复杂度为 O(n + m)。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_union;
+    /// assert_eq!(sorted_union(&[1, 2, 2, 4], &[2, 3]), [1, 2, 3, 4]);
+    /// ```
+    sorted_union,
+    /// 求两个有序数组并集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_union_iter;
+    /// let mut iter = sorted_union_iter(&[1, 2, 2, 4], &[2, 3]);
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// ```
+    sorted_union_iter,
+    Union,
+    false
+);
+
+sorted_set_operation!(
+    /// 求两个有序数组的交集，结果中每个值只出现一次
+    ///
+    /// 两个数组长度相近时时间复杂度为 O(n + m)；长度相差较大时在较长数组中使用指数查找跳过元素，
+    /// 时间复杂度为 O(nlog(m / n))，其中 n 为较短数组的长度。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_intersection;
+    /// assert_eq!(sorted_intersection(&[1, 2, 2, 4], &[2, 2, 3, 4]), [2, 4]);
+    /// ```
+    sorted_intersection,
+    /// 求两个有序数组交集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_intersection_iter;
+    /// let mut iter = sorted_intersection_iter(&[1, 2, 2, 4], &[2, 2, 3, 4]);
+    /// assert_eq!(iter.next(), Some(&2));
+    /// ```
+    sorted_intersection_iter,
+    Intersection,
+    false
+);
+
+sorted_set_operation!(
+    /// 求两个有序数组的差集，即只在第一个数组中出现的值，结果中每个值只出现一次
+    ///
+    /// 时间复杂度为 O(n + m)。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_difference;
+    /// assert_eq!(sorted_difference(&[1, 1, 2, 4], &[2, 3]), [1, 4]);
+    /// ```
+    sorted_difference,
+    /// 求两个有序数组差集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_difference_iter;
+    /// let mut iter = sorted_difference_iter(&[1, 1, 2, 4], &[2, 3]);
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// ```
+    sorted_difference_iter,
+    Difference,
+    false
+);
+
+sorted_set_operation!(
+    /// 求两个有序数组的对称差集，即只在其中一个数组中出现的值，结果中每个值只出现一次
+    ///
+    /// 时间复杂度为 O(n + m)。
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_symmetric_difference;
+    /// assert_eq!(sorted_symmetric_difference(&[1, 2, 4], &[2, 3]), [1, 3, 4]);
+    /// ```
+    sorted_symmetric_difference,
+    /// 求两个有序数组对称差集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_symmetric_difference_iter;
+    /// let mut iter = sorted_symmetric_difference_iter(&[1, 2, 4], &[2, 3]);
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// ```
+    sorted_symmetric_difference_iter,
+    SymmetricDifference,
+    false
+);
+
+sorted_set_operation!(
+    /// 求两个有序数组的多重集并集，每个值出现的次数为其在两个数组中出现次数的较大值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_union;
+    /// assert_eq!(sorted_multiset_union(&[1, 2, 2], &[2, 2, 2, 3]), [1, 2, 2, 2, 3]);
+    /// ```
+    sorted_multiset_union,
+    /// 求两个有序数组多重集并集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_union_iter;
+    /// assert_eq!(sorted_multiset_union_iter(&[1, 2, 2], &[2, 2, 2, 3]).count(), 5);
+    /// ```
+    sorted_multiset_union_iter,
+    Union,
+    true
+);
+
+sorted_set_operation!(
+    /// 求两个有序数组的多重集交集，每个值出现的次数为其在两个数组中出现次数的较小值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_intersection;
+    /// assert_eq!(sorted_multiset_intersection(&[1, 2, 2], &[2, 2, 2, 3]), [2, 2]);
+    /// ```
+    sorted_multiset_intersection,
+    /// 求两个有序数组多重集交集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_intersection_iter;
+    /// assert_eq!(sorted_multiset_intersection_iter(&[1, 2, 2], &[2, 2, 2, 3]).count(), 2);
+    /// ```
+    sorted_multiset_intersection_iter,
+    Intersection,
+    true
+);
+
+sorted_set_operation!(
+    /// 求两个有序数组的多重集差集，每个值出现的次数为其在第一个数组中的出现次数减去在第二个数组中的出现次数
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_difference;
+    /// assert_eq!(sorted_multiset_difference(&[1, 2, 2, 2], &[2, 3]), [1, 2, 2]);
+    /// ```
+    sorted_multiset_difference,
+    /// 求两个有序数组多重集差集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_difference_iter;
+    /// assert_eq!(sorted_multiset_difference_iter(&[1, 2, 2, 2], &[2, 3]).count(), 3);
+    /// ```
+    sorted_multiset_difference_iter,
+    Difference,
+    true
+);
+
+sorted_set_operation!(
+    /// 求两个有序数组的多重集对称差集，每个值出现的次数为其在两个数组中出现次数之差的绝对值
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_symmetric_difference;
+    /// assert_eq!(sorted_multiset_symmetric_difference(&[1, 2], &[2, 2, 2, 3]), [1, 2, 2, 3]);
+    /// ```
+    sorted_multiset_symmetric_difference,
+    /// 求两个有序数组多重集对称差集的惰性迭代器
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_demo::set::sorted_multiset_symmetric_difference_iter;
+    /// assert_eq!(sorted_multiset_symmetric_difference_iter(&[1, 2], &[2, 2, 2, 3]).count(), 4);
+    /// ```
+    sorted_multiset_symmetric_difference_iter,
+    SymmetricDifference,
+    true
+);
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::sort::{quick_sort, random_vec};
+
+    fn counts(arr: &[u64]) -> BTreeMap<u64, usize> {
+        let mut result = BTreeMap::new();
+        for it in arr {
+            *result.entry(*it).or_insert(0) += 1;
+        }
+        result
+    }
+
+    /// 根据每个值在两个数组中的出现次数计算期望结果
+    fn expected(a: &[u64], b: &[u64], f: impl Fn(usize, usize) -> usize) -> Vec<u64> {
+        let (ca, cb) = (counts(a), counts(b));
+        let mut keys: Vec<u64> = ca.keys().chain(cb.keys()).copied().collect();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .flat_map(|k| {
+                let n = f(*ca.get(&k).unwrap_or(&0), *cb.get(&k).unwrap_or(&0));
+                core::iter::repeat_n(k, n)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let empty: [i32; 0] = [];
+        assert_eq!(sorted_union(&empty, &empty), empty);
+        assert_eq!(sorted_union(&[1], &empty), [1]);
+        assert_eq!(sorted_intersection(&[1], &empty), empty);
+        assert_eq!(sorted_difference(&empty, &[1]), empty);
+        assert_eq!(sorted_symmetric_difference(&empty, &[1]), [1]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = [1, 1, 2, 3, 5, 8];
+        let b = [2, 3, 3, 4, 5];
+        assert_eq!(sorted_union(&a, &b), [1, 2, 3, 4, 5, 8]);
+        assert_eq!(sorted_intersection(&a, &b), [2, 3, 5]);
+        assert_eq!(sorted_difference(&a, &b), [1, 8]);
+        assert_eq!(sorted_difference(&b, &a), [4]);
+        assert_eq!(sorted_symmetric_difference(&a, &b), [1, 4, 8]);
+    }
+
+    #[test]
+    fn multiset_operations() {
+        let a = [1, 1, 2, 3, 5, 8];
+        let b = [2, 3, 3, 4, 5];
+        assert_eq!(sorted_multiset_union(&a, &b), [1, 1, 2, 3, 3, 4, 5, 8]);
+        assert_eq!(sorted_multiset_intersection(&a, &b), [2, 3, 5]);
+        assert_eq!(sorted_multiset_difference(&a, &b), [1, 1, 8]);
+        assert_eq!(
+            sorted_multiset_symmetric_difference(&a, &b),
+            [1, 1, 3, 4, 8]
+        );
+    }
+
+    #[test]
+    fn lazy() {
+        let a: Vec<u32> = (0..u32::MAX).step_by(2).take(1000).collect();
+        let b: Vec<u32> = (0..3000).step_by(3).collect();
+        let first: Vec<&u32> = sorted_intersection_iter(&a, &b).take(3).collect();
+        assert_eq!(first, [&0, &6, &12]);
+
+        let iter = sorted_union_iter(&[1, 2], &[3]);
+        assert_eq!(iter.size_hint(), (0, Some(3)));
+    }
+
+    #[test]
+    fn gallop() {
+        let a = [5, 500, 5000, 9999];
+        let b: Vec<i32> = (0..10_000).collect();
+        assert_eq!(sorted_intersection(&a, &b), a);
+        assert_eq!(sorted_intersection(&b, &a), a);
+        assert_eq!(sorted_intersection(&[10_001], &b), [] as [i32; 0]);
+    }
+
+    #[test]
+    fn random() {
+        for (len_a, len_b) in [(0, 10), (10, 10), (100, 1000), (1000, 10), (10, 5000)] {
+            let mut a: Vec<u64> = random_vec(len_a, len_a as u64 + 1)
+                .into_iter()
+                .map(|x| x % 100)
+                .collect();
+            let mut b: Vec<u64> = random_vec(len_b, len_b as u64 + 2)
+                .into_iter()
+                .map(|x| x % 100)
+                .collect();
+            quick_sort(&mut a);
+            quick_sort(&mut b);
+
+            let set =
+                |f: fn(bool, bool) -> bool| move |x: usize, y: usize| f(x > 0, y > 0) as usize;
+            assert_eq!(sorted_union(&a, &b), expected(&a, &b, set(|x, y| x || y)));
+            assert_eq!(
+                sorted_intersection(&a, &b),
+                expected(&a, &b, set(|x, y| x && y))
+            );
+            assert_eq!(
+                sorted_difference(&a, &b),
+                expected(&a, &b, set(|x, y| x && !y))
+            );
+            assert_eq!(
+                sorted_symmetric_difference(&a, &b),
+                expected(&a, &b, set(|x, y| x != y))
+            );
+
+            assert_eq!(sorted_multiset_union(&a, &b), expected(&a, &b, usize::max));
+            assert_eq!(
+                sorted_multiset_intersection(&a, &b),
+                expected(&a, &b, usize::min)
+            );
+            assert_eq!(
+                sorted_multiset_difference(&a, &b),
+                expected(&a, &b, usize::saturating_sub)
+            );
+            assert_eq!(
+                sorted_multiset_symmetric_difference(&a, &b),
+                expected(&a, &b, usize::abs_diff)
+            );
+        }
+    }
+}
@@ -1,9 +1,9 @@
 /// 冒泡排序
-/// 
+///
 /// 稳定排序算法，平均时间复杂度为 O(n^2)，空间复杂度为 O(1)。
 ///
 /// # Examples
-/// 
+///
 /// ```
 /// use rust_demo::sort::bubble_sort;
 /// let mut arr = [2, 3, 1];
@@ -37,6 +37,8 @@ where
 mod tests {
     use super::super::is_sort;
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn reverse_sorted() {
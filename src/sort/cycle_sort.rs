@@ -0,0 +1,145 @@
+use core::mem;
+
+/// 圈排序
+///
+/// 将数组分解为若干个置换环，每个元素直接写入其最终位置，
+/// 写入数组的次数是所有排序算法中最少的，适用于写入代价远高于读取的场景。
+/// 不稳定排序，平均时间复杂度为 O(n^2)，空间复杂度为 O(1)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::cycle_sort;
+/// let mut arr = [2, 1, 3];
+/// cycle_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn cycle_sort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    cycle_sort_with_writes(arr);
+}
+
+/// 圈排序，并返回排序过程中写入数组元素的次数
+///
+/// 已在最终位置的元素不会被写入，其余元素各写入一次，因此写入次数不超过 n。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::cycle_sort_with_writes;
+/// let mut arr = [3, 1, 2, 4];
+/// assert_eq!(cycle_sort_with_writes(&mut arr), 3);
+/// assert_eq!(arr, [1, 2, 3, 4]);
+/// ```
+pub fn cycle_sort_with_writes<T>(arr: &mut [T]) -> usize
+where
+    T: Ord + Clone,
+{
+    let mut writes = 0;
+    for start in 0..arr.len().saturating_sub(1) {
+        let mut item = arr[start].clone();
+        let mut pos = position(arr, start, &item);
+        if pos == start {
+            continue;
+        }
+
+        // 沿置换环依次将元素放入最终位置，直到回到起点
+        loop {
+            // 跳过与当前元素相等的元素，保证重复元素各自占据一个位置
+            while item == arr[pos] {
+                pos += 1;
+            }
+            mem::swap(&mut item, &mut arr[pos]);
+            writes += 1;
+
+            if pos == start {
+                break;
+            }
+            pos = position(arr, start, &item);
+        }
+    }
+    writes
+}
+
+/// 元素在 arr[start..] 中的最终位置，即 start 加上其后小于该元素的个数
+fn position<T>(arr: &[T], start: usize, item: &T) -> usize
+where
+    T: Ord,
+{
+    start + arr[start + 1..].iter().filter(|it| *it < item).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{is_sort, random_vec};
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let mut arr: Vec<i32> = vec![];
+        cycle_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn reverse_sorted() {
+        let mut arr = vec![6, 5, 4, 3, 2, 1];
+        cycle_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn sorted() {
+        let mut arr = vec![1, 2, 3, 4, 5, 6];
+        cycle_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn one_element() {
+        let mut arr = vec![1];
+        cycle_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn repeat() {
+        let mut arr = vec![2, 2, 1, 3, 3, 1];
+        cycle_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn random() {
+        for len in 0..100 {
+            let mut arr = random_vec(len, len as u64 + 1);
+            let mut expected = arr.clone();
+            expected.sort();
+            cycle_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn writes() {
+        let mut arr = vec![1, 2, 3, 4, 5, 6];
+        assert_eq!(cycle_sort_with_writes(&mut arr), 0);
+
+        let mut arr = vec![6, 5, 4, 3, 2, 1];
+        assert_eq!(cycle_sort_with_writes(&mut arr), 6);
+        assert!(is_sort(&arr));
+
+        // 写入次数恰好等于不在最终位置上的元素个数
+        for len in 1..100 {
+            let mut arr = random_vec(len, len as u64 + 1);
+            let mut expected = arr.clone();
+            expected.sort();
+            let misplaced = arr.iter().zip(&expected).filter(|(x, y)| x != y).count();
+            assert_eq!(cycle_sort_with_writes(&mut arr), misplaced);
+        }
+    }
+}
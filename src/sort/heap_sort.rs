@@ -3,7 +3,7 @@
 /// 不稳定排序，平均时间复杂度为 O(nlogn)，空间复杂度为 O(1)。
 ///
 /// # Example
-/// 
+///
 /// ```
 /// use rust_demo::sort::heap_sort;
 /// let mut arr = [2, 1, 3];
@@ -33,7 +33,7 @@ where
     T: Ord,
 {
     let end = arr.len() - 1;
-    while 2 * root + 1 <= end {
+    while 2 * root < end {
         let mut idx = 2 * root + 1;
         if let Some(right) = arr.get(idx + 1) {
             if arr[idx] < *right {
@@ -48,10 +48,73 @@ where
     }
 }
 
+/// 自底向上的堆排序
+///
+/// 下沉时先沿较大的子节点一路下降到叶子节点，再向上回溯找到根节点元素的位置，
+/// 每层只需一次比较，比较次数接近 nlogn。
+/// 不稳定排序，平均时间复杂度为 O(nlogn)，空间复杂度为 O(1)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::bottom_up_heap_sort;
+/// let mut arr = [2, 1, 3];
+/// bottom_up_heap_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn bottom_up_heap_sort<T>(arr: &mut [T])
+where
+    T: Ord,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    for k in (0..=((arr.len() - 2) / 2)).rev() {
+        bottom_up_sink(arr, k);
+    }
+
+    for k in (1..arr.len()).rev() {
+        arr.swap(0, k);
+        bottom_up_sink(&mut arr[..k], 0);
+    }
+}
+
+fn bottom_up_sink<T>(arr: &mut [T], root: usize)
+where
+    T: Ord,
+{
+    // 沿较大的子节点下降到叶子节点
+    let mut leaf = root;
+    while 2 * leaf + 2 < arr.len() {
+        leaf = if arr[2 * leaf + 1] < arr[2 * leaf + 2] {
+            2 * leaf + 2
+        } else {
+            2 * leaf + 1
+        };
+    }
+    if 2 * leaf + 1 < arr.len() {
+        leaf = 2 * leaf + 1;
+    }
+
+    // 向上回溯，找到第一个不小于根节点元素的位置
+    while arr[root] > arr[leaf] {
+        leaf = (leaf - 1) / 2;
+    }
+
+    // 路径上的元素依次上移一层，根节点元素放入找到的位置
+    while leaf > root {
+        arr.swap(root, leaf);
+        leaf = (leaf - 1) / 2;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::is_sort;
+    use super::super::{is_sort, random_vec};
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn empty() {
@@ -87,4 +150,39 @@ mod tests {
         heap_sort(&mut arr);
         assert!(is_sort(&arr));
     }
+
+    #[test]
+    fn bottom_up() {
+        let mut arr: Vec<i32> = vec![];
+        bottom_up_heap_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr = vec![1];
+        bottom_up_heap_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr = vec![6, 5, 4, 3, 2, 1];
+        bottom_up_heap_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr = vec![2, 2, 1, 3, 3, 1];
+        bottom_up_heap_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn random() {
+        for len in 0..200 {
+            let mut arr1 = random_vec(len, len as u64 + 1);
+            let mut arr2 = arr1.clone();
+            let mut expected = arr1.clone();
+            expected.sort();
+
+            heap_sort(&mut arr1);
+            assert_eq!(arr1, expected);
+
+            bottom_up_heap_sort(&mut arr2);
+            assert_eq!(arr2, expected);
+        }
+    }
 }
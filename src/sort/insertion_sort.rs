@@ -1,9 +1,9 @@
 /// 插入排序
-/// 
+///
 /// 稳定排序算法，平均时间复杂度为 O(n^2)，空间复杂度为 O(1)。
-/// 
+///
 /// # Example
-/// 
+///
 /// ```
 /// use rust_demo::sort::insertion_sort;
 /// let mut arr = [2, 1, 3];
@@ -42,6 +42,8 @@ where
 mod tests {
     use super::super::is_sort;
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn reverse_sorted() {
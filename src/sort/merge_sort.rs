@@ -1,11 +1,11 @@
-use std::cmp::min;
+use core::cmp::min;
 
 /// 自顶向下的归并排序
-/// 
+///
 /// 稳定排序算法，平均时间复杂度为 O(nlogn)，空间复杂度为 O(n)。
-/// 
+///
 /// # Example
-/// 
+///
 /// ```
 /// use rust_demo::sort::td_merge_sort;
 /// let mut arr = [2, 1, 3];
@@ -38,7 +38,7 @@ where
 
     for x in arr {
         if right_idx >= right_half.len()
-            || (left_idx < left_half.len() && left_half[left_idx] < right_half[right_idx])
+            || (left_idx < left_half.len() && left_half[left_idx] <= right_half[right_idx])
         {
             *x = left_half[left_idx];
             left_idx += 1;
@@ -50,11 +50,11 @@ where
 }
 
 /// 自底向上的归并排序
-/// 
+///
 /// 稳定排序算法，平均时间复杂度为 O(nlogn)，空间复杂度为 O(n)。
-/// 
+///
 /// # Example
-/// 
+///
 /// ```
 /// use rust_demo::sort::bu_merge_sort;
 /// let mut arr = [2, 1, 3];
@@ -84,12 +84,12 @@ where
     let mut left_idx = start;
     let mut right_idx = mid + 1;
 
-    for x in start..end {
-        if right_idx >= end || (left_idx <= mid && temp_copy[left_idx] < temp_copy[right_idx]) {
-            arr[x] = temp_copy[left_idx];
+    for x in &mut arr[start..end] {
+        if right_idx >= end || (left_idx <= mid && temp_copy[left_idx] <= temp_copy[right_idx]) {
+            *x = temp_copy[left_idx];
             left_idx += 1;
         } else {
-            arr[x] = temp_copy[right_idx];
+            *x = temp_copy[right_idx];
             right_idx += 1;
         }
     }
@@ -99,6 +99,8 @@ where
 mod tests {
     use super::super::is_sort;
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn empty() {
@@ -154,4 +156,59 @@ mod tests {
         bu_merge_sort(&mut arr2);
         assert!(is_sort(&arr2));
     }
+
+    /// 只按第一个字段比较大小，第二个字段记录原始位置
+    #[derive(Clone, Copy, Debug)]
+    struct Item(i32, usize);
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Item {}
+
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn stable() {
+        let input: Vec<Item> = [2, 1, 2, 1, 2, 1]
+            .iter()
+            .enumerate()
+            .map(|(idx, key)| Item(*key, idx))
+            .collect();
+        let expected = [
+            Item(1, 1),
+            Item(1, 3),
+            Item(1, 5),
+            Item(2, 0),
+            Item(2, 2),
+            Item(2, 4),
+        ];
+
+        let mut arr1 = input.clone();
+        td_merge_sort(&mut arr1);
+        assert!(arr1
+            .iter()
+            .map(|it| it.1)
+            .eq(expected.iter().map(|it| it.1)));
+
+        let mut arr2 = input.clone();
+        bu_merge_sort(&mut arr2);
+        assert!(arr2
+            .iter()
+            .map(|it| it.1)
+            .eq(expected.iter().map(|it| it.1)));
+    }
 }
@@ -1,18 +1,38 @@
 pub use self::bubble_sort::bubble_sort;
-pub use self::heap_sort::heap_sort;
+pub use self::cycle_sort::{cycle_sort, cycle_sort_with_writes};
+pub use self::heap_sort::{bottom_up_heap_sort, heap_sort};
 pub use self::insertion_sort::insertion_sort;
+#[cfg(feature = "alloc")]
 pub use self::merge_sort::{bu_merge_sort, td_merge_sort};
-pub use self::quick_sort::quick_sort;
-pub use self::selection_sort::selection_sort;
+pub use self::quick_sort::{block_quick_sort, quick_sort};
+#[cfg(feature = "std")]
+pub use self::sample_sort::parallel_sample_sort;
+#[cfg(feature = "alloc")]
+pub use self::sample_sort::sample_sort;
+pub use self::selection_sort::{selection_sort, selection_sort_with_writes};
 pub use self::shell_sort::shell_sort;
+pub use self::smooth_sort::smooth_sort;
+#[cfg(feature = "alloc")]
+pub use self::sorted_iter::{sorted_iter, SortedIter};
+#[cfg(feature = "alloc")]
+pub use self::string_sort::{lcp_merge_sort, multikey_quick_sort};
 
 mod bubble_sort;
+mod cycle_sort;
 mod heap_sort;
 mod insertion_sort;
+#[cfg(feature = "alloc")]
 mod merge_sort;
 mod quick_sort;
+#[cfg(feature = "alloc")]
+mod sample_sort;
 mod selection_sort;
 mod shell_sort;
+mod smooth_sort;
+#[cfg(feature = "alloc")]
+mod sorted_iter;
+#[cfg(feature = "alloc")]
+mod string_sort;
 
 #[allow(dead_code)]
 fn is_sort<T>(arr: &[T]) -> bool
@@ -33,6 +53,21 @@ where
     true
 }
 
+/// 生成指定长度的伪随机数组，元素取值范围为 [0, 1000)，便于产生重复元素
+#[cfg(test)]
+pub(crate) fn random_vec(len: usize, seed: u64) -> alloc::vec::Vec<u64> {
+    let mut state = seed.max(1);
+    (0..len)
+        .map(|_| {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % 1000
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,7 +79,7 @@ mod tests {
         assert!(is_sort(&[1, 2, 3]));
         assert!(is_sort(&[0, 1, 1]));
 
-        assert_eq!(is_sort(&[1, 0]), false);
-        assert_eq!(is_sort(&[2, 3, 1, -1, 5]), false);
+        assert!(!is_sort(&[1, 0]));
+        assert!(!is_sort(&[2, 3, 1, -1, 5]));
     }
 }
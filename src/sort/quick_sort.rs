@@ -1,3 +1,5 @@
+use core::mem;
+
 /// 快速排序
 ///
 /// 不稳定排序，平均时间复杂度为 O(nlogn)，空间复杂度为 O(logn)。
@@ -39,7 +41,7 @@ fn partition<T>(arr: &mut [T], start: usize, end: usize) -> usize
 where
     T: Ord,
 {
-    let mid = median(arr, start, start + (end - start + 1) / 2, end);
+    let mid = median(arr, start, start + (end - start).div_ceil(2), end);
     arr.swap(start, mid);
 
     let mut l_idx = start + 1;
@@ -64,6 +66,9 @@ where
 
     while l_idx < r_idx {
         arr.swap(l_idx, r_idx);
+        // 交换后的两个元素已在正确一侧，需跳过，否则两侧都等于基准时会陷入死循环
+        l_idx += 1;
+        r_idx -= 1;
         while arr[l_idx] < arr[start] {
             l_idx += 1;
         }
@@ -76,6 +81,121 @@ where
     r_idx
 }
 
+/// 块快速排序（BlockQuicksort）
+///
+/// 切分时先将一整块元素与基准的比较结果记录到偏移量数组中，再统一交换，
+/// 比较过程没有依赖于数据的分支，避免随机数据下大量的分支预测失败。
+/// 不稳定排序，平均时间复杂度为 O(nlogn)，空间复杂度为 O(logn)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::block_quick_sort;
+/// let mut arr = [2, 1, 3];
+/// block_quick_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn block_quick_sort<T>(mut arr: &mut [T])
+where
+    T: Ord,
+{
+    // 只对较短的一侧递归，较长的一侧继续循环处理，递归深度不超过 O(logn)
+    while arr.len() > 1 {
+        let pivot = block_partition(arr);
+        let (left, right) = mem::take(&mut arr).split_at_mut(pivot);
+        let right = &mut right[1..];
+        if left.len() < right.len() {
+            block_quick_sort(left);
+            arr = right;
+        } else {
+            block_quick_sort(right);
+            arr = left;
+        }
+    }
+}
+
+/// 每块的元素个数，偏移量使用 u8 存储
+const BLOCK: usize = 128;
+
+fn block_partition<T>(arr: &mut [T]) -> usize
+where
+    T: Ord,
+{
+    let mid = median(arr, 0, arr.len() / 2, arr.len() - 1);
+    arr.swap(0, mid);
+
+    let (pivot, rest) = arr.split_at_mut(1);
+    let pivot = &pivot[0];
+
+    // 不变式：rest[..l] <= pivot，rest[r..] >= pivot
+    let mut l = 0;
+    let mut r = rest.len();
+
+    let mut offsets_l = [0u8; BLOCK];
+    let mut offsets_r = [0u8; BLOCK];
+    let (mut start_l, mut num_l) = (0, 0);
+    let (mut start_r, mut num_r) = (0, 0);
+
+    while r - l > 2 * BLOCK {
+        // 记录左侧块中不小于基准的元素偏移量
+        if num_l == 0 {
+            start_l = 0;
+            for i in 0..BLOCK {
+                offsets_l[num_l] = i as u8;
+                num_l += (rest[l + i] >= *pivot) as usize;
+            }
+        }
+        // 记录右侧块中不大于基准的元素偏移量
+        if num_r == 0 {
+            start_r = 0;
+            for i in 0..BLOCK {
+                offsets_r[num_r] = i as u8;
+                num_r += (rest[r - 1 - i] <= *pivot) as usize;
+            }
+        }
+
+        // 两侧需要交换的元素一一配对交换
+        let num = num_l.min(num_r);
+        for j in 0..num {
+            rest.swap(
+                l + offsets_l[start_l + j] as usize,
+                r - 1 - offsets_r[start_r + j] as usize,
+            );
+        }
+        num_l -= num;
+        num_r -= num;
+        start_l += num;
+        start_r += num;
+
+        if num_l == 0 {
+            l += BLOCK;
+        }
+        if num_r == 0 {
+            r -= BLOCK;
+        }
+    }
+
+    // 剩余不足两块的元素使用普通的双向切分
+    loop {
+        while l < r && rest[l] < *pivot {
+            l += 1;
+        }
+        while l < r && rest[r - 1] > *pivot {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        r -= 1;
+        rest.swap(l, r);
+        l += 1;
+    }
+
+    // rest[..l] <= pivot，基准放到 rest[l - 1] 之后
+    arr.swap(0, l);
+    l
+}
+
 fn median<T>(arr: &[T], idx1: usize, idx2: usize, idx3: usize) -> usize
 where
     T: Ord,
@@ -100,8 +220,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::super::is_sort;
+    use super::super::{is_sort, random_vec};
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn empty() {
@@ -137,4 +259,54 @@ mod tests {
         quick_sort(&mut arr);
         assert!(is_sort(&arr));
     }
+
+    #[test]
+    fn block() {
+        let mut arr: Vec<i32> = vec![];
+        block_quick_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr = vec![1];
+        block_quick_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr = vec![1, 2, 3, 4, 5, 6];
+        block_quick_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr = vec![6, 5, 4, 3, 2, 1];
+        block_quick_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr = vec![2, 2, 1, 3, 3, 1];
+        block_quick_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn random() {
+        for len in [0, 1, 2, 3, 100, 255, 256, 257, 1000, 5000] {
+            let mut arr1 = random_vec(len, len as u64 + 1);
+            let mut arr2 = arr1.clone();
+            let mut expected = arr1.clone();
+            expected.sort();
+
+            quick_sort(&mut arr1);
+            assert_eq!(arr1, expected);
+
+            block_quick_sort(&mut arr2);
+            assert_eq!(arr2, expected);
+        }
+    }
+
+    #[test]
+    fn block_all_equal() {
+        let mut arr = vec![7; 5000];
+        block_quick_sort(&mut arr);
+        assert!(is_sort(&arr));
+
+        let mut arr: Vec<u64> = random_vec(5000, 3).into_iter().map(|x| x % 3).collect();
+        block_quick_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
 }
@@ -0,0 +1,293 @@
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "std")]
+use std::thread;
+
+use super::quick_sort;
+
+/// 样本排序
+///
+/// 从数组中随机抽取样本并排序，按固定间隔选出分隔元素，再通过分隔元素构成的二叉搜索树
+/// 一次遍历将所有元素分到多个桶中，最后对每个桶递归排序。相比于快速排序，
+/// 每层一次性切分出多个桶，对数组的扫描次数更少，适用于远大于缓存的数组。
+/// 不稳定排序，平均时间复杂度为 O(nlogn)，空间复杂度为 O(n)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::sample_sort;
+/// let mut arr = [2, 1, 3];
+/// sample_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn sample_sort<T>(arr: &mut [T])
+where
+    T: Ord + Clone,
+{
+    if arr.len() <= BASE_CASE {
+        quick_sort(arr);
+        return;
+    }
+
+    let mut buckets = distribute(arr);
+    // 所有元素都落入同一个桶时无法继续切分（例如大量重复元素），改用快速排序
+    if buckets.len() == 1 {
+        quick_sort(buckets.pop().unwrap());
+        return;
+    }
+    for bucket in buckets {
+        sample_sort(bucket);
+    }
+}
+
+/// 并行样本排序
+///
+/// 与 [`sample_sort`] 相同，但在第一次分桶后由多个线程并行地对各个桶排序。
+/// 需要启用 `std` feature。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::parallel_sample_sort;
+/// let mut arr = [2, 1, 3];
+/// parallel_sample_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn parallel_sample_sort<T>(arr: &mut [T])
+where
+    T: Ord + Clone + Send,
+{
+    if arr.len() <= BASE_CASE {
+        quick_sort(arr);
+        return;
+    }
+
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    // 按桶的大小降序处理，让较大的桶尽早开始，减少线程的空闲等待
+    let mut buckets = distribute(arr);
+    buckets.sort_by_key(|bucket| bucket.len());
+    let queue = Mutex::new(buckets);
+
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| loop {
+                let bucket = queue.lock().unwrap().pop();
+                match bucket {
+                    None => break,
+                    Some(bucket) => sample_sort(bucket),
+                }
+            });
+        }
+    });
+}
+
+/// 数组长度不超过该值时直接使用快速排序
+const BASE_CASE: usize = 4096;
+
+/// 每层切分出 2^LOG_BUCKETS 个桶
+const LOG_BUCKETS: usize = 8;
+const BUCKETS: usize = 1 << LOG_BUCKETS;
+
+/// 过采样系数，每个分隔元素对应的样本数
+const OVERSAMPLING: usize = 4;
+
+/// 将数组按分隔元素原地分为多个桶，返回各个非空桶
+fn distribute<T>(arr: &mut [T]) -> Vec<&mut [T]>
+where
+    T: Ord + Clone,
+{
+    let tree = splitter_tree(arr);
+
+    // 记录每个元素所属的桶，并统计每个桶的大小
+    let mut oracle = vec![0u8; arr.len()];
+    let mut sizes = [0usize; BUCKETS];
+    for (it, bucket) in arr.iter().zip(oracle.iter_mut()) {
+        let idx = classify(&tree, it);
+        *bucket = idx as u8;
+        sizes[idx] += 1;
+    }
+
+    let mut starts = [0usize; BUCKETS];
+    let mut ends = [0usize; BUCKETS];
+    let mut sum = 0;
+    for idx in 0..BUCKETS {
+        starts[idx] = sum;
+        sum += sizes[idx];
+        ends[idx] = sum;
+    }
+
+    // 沿置换环将元素交换到所属的桶中
+    let mut next = starts;
+    for idx in 0..BUCKETS {
+        while next[idx] < ends[idx] {
+            let pos = next[idx];
+            let target = oracle[pos] as usize;
+            if target == idx {
+                next[idx] += 1;
+            } else {
+                arr.swap(pos, next[target]);
+                oracle.swap(pos, next[target]);
+                next[target] += 1;
+            }
+        }
+    }
+
+    let mut result = Vec::new();
+    let mut rest = arr;
+    for size in sizes {
+        let (bucket, tail) = rest.split_at_mut(size);
+        if !bucket.is_empty() {
+            result.push(bucket);
+        }
+        rest = tail;
+    }
+    result
+}
+
+/// 随机抽样并排序，选出 BUCKETS - 1 个分隔元素，按完全二叉树的层序存储
+fn splitter_tree<T>(arr: &[T]) -> Vec<T>
+where
+    T: Ord + Clone,
+{
+    let mut state = arr.len() as u64 | 1;
+    let mut sample: Vec<T> = (0..OVERSAMPLING * BUCKETS - 1)
+        .map(|_| {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            arr[(state % arr.len() as u64) as usize].clone()
+        })
+        .collect();
+    quick_sort(&mut sample);
+
+    // 层序下标为 j 的节点在中序遍历（即有序）中的位置
+    (0..BUCKETS - 1)
+        .map(|j| {
+            let depth = (j + 1).ilog2() as usize;
+            let pos = j + 1 - (1 << depth);
+            let rank = (2 * pos + 1) * (1 << (LOG_BUCKETS - depth - 1)) - 1;
+            sample[(rank + 1) * OVERSAMPLING - 1].clone()
+        })
+        .collect()
+}
+
+/// 在分隔元素树中查找元素所属的桶，每层只根据比较结果计算下标，没有分支
+fn classify<T>(tree: &[T], item: &T) -> usize
+where
+    T: Ord,
+{
+    let mut j = 0;
+    for _ in 0..LOG_BUCKETS {
+        j = 2 * j + 1 + (tree[j] < *item) as usize;
+    }
+    j - tree.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{is_sort, random_vec};
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let mut arr: Vec<i32> = vec![];
+        sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn reverse_sorted() {
+        let mut arr: Vec<usize> = (0..100_000).rev().collect();
+        sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn sorted() {
+        let mut arr: Vec<usize> = (0..100_000).collect();
+        sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn one_element() {
+        let mut arr = vec![1];
+        sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn repeat() {
+        let mut arr = vec![2, 2, 1, 3, 3, 1];
+        sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn random() {
+        for len in [100, 4096, 4097, 10_000, 100_000] {
+            let mut expected = random_vec(len, len as u64);
+            let mut arr = expected.clone();
+            expected.sort();
+
+            sample_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parallel_empty() {
+        let mut arr: Vec<i32> = vec![];
+        parallel_sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parallel_sorted_and_reverse_sorted() {
+        let mut arr1: Vec<usize> = (0..100_000).rev().collect();
+        parallel_sample_sort(&mut arr1);
+        assert!(is_sort(&arr1));
+
+        let mut arr2: Vec<usize> = (0..100_000).collect();
+        parallel_sample_sort(&mut arr2);
+        assert!(is_sort(&arr2));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parallel_one_element() {
+        let mut arr = vec![1];
+        parallel_sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parallel_repeat() {
+        let mut arr = vec![7; 100_000];
+        arr[500] = 1;
+        parallel_sample_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parallel_random() {
+        for len in [100, 4096, 4097, 10_000, 100_000] {
+            let mut expected = random_vec(len, len as u64);
+            let mut arr = expected.clone();
+            expected.sort();
+
+            parallel_sample_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+}
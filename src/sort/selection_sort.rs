@@ -1,9 +1,9 @@
 /// 选择排序
-/// 
+///
 /// 不稳定排序算法，平均时间复杂度为 O(n^2)，空间复杂度为 O(1)。
-/// 
+///
 /// # Example
-/// 
+///
 /// ```
 /// use rust_demo::sort::selection_sort;
 /// let mut arr = [2, 1, 3];
@@ -14,22 +14,48 @@ pub fn selection_sort<T>(arr: &mut [T])
 where
     T: Ord,
 {
+    selection_sort_with_writes(arr);
+}
+
+/// 选择排序，并返回排序过程中写入数组元素的次数
+///
+/// 每次交换计为两次写入，最小值已在正确位置时不进行交换，因此写入次数不超过 2(n - 1)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::selection_sort_with_writes;
+/// let mut arr = [2, 1, 3];
+/// assert_eq!(selection_sort_with_writes(&mut arr), 2);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn selection_sort_with_writes<T>(arr: &mut [T]) -> usize
+where
+    T: Ord,
+{
+    let mut writes = 0;
     let len = arr.len();
     for left_idx in 0..len {
         let mut smallest_idx = left_idx;
         for right_idx in (left_idx + 1)..len {
-            if arr[left_idx] > arr[right_idx] {
+            if arr[smallest_idx] > arr[right_idx] {
                 smallest_idx = right_idx;
             }
         }
-        arr.swap(smallest_idx, left_idx);
+        if smallest_idx != left_idx {
+            arr.swap(smallest_idx, left_idx);
+            writes += 2;
+        }
     }
+    writes
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::is_sort;
+    use super::super::{is_sort, random_vec};
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn reverse_sorted() {
@@ -65,4 +91,25 @@ mod tests {
         selection_sort(&mut arr);
         assert!(is_sort(&arr));
     }
+
+    #[test]
+    fn random() {
+        for len in 0..100 {
+            let mut arr = random_vec(len, len as u64 + 1);
+            let mut expected = arr.clone();
+            expected.sort();
+            selection_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn writes() {
+        let mut arr = vec![1, 2, 3, 4, 5, 6];
+        assert_eq!(selection_sort_with_writes(&mut arr), 0);
+
+        let mut arr = vec![6, 5, 4, 3, 2, 1];
+        assert_eq!(selection_sort_with_writes(&mut arr), 6);
+        assert!(is_sort(&arr));
+    }
 }
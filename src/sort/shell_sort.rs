@@ -1,9 +1,9 @@
 /// 希尔排序
-/// 
+///
 /// 不稳定排序算法，平均时间复杂度为 O(nlogn)，空间复杂度为 O(1)。
-/// 
+///
 /// # Example
-/// 
+///
 /// ```
 /// use rust_demo::sort::shell_sort;
 /// let mut arr = [2, 1, 3];
@@ -35,6 +35,8 @@ where
 mod tests {
     use super::super::is_sort;
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn empty() {
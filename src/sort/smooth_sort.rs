@@ -0,0 +1,198 @@
+/// 平滑排序
+///
+/// Dijkstra 提出的堆排序变种，使用一组 Leonardo 堆代替二叉堆。
+/// 不稳定排序，平均时间复杂度为 O(nlogn)，输入接近有序时趋近于 O(n)，空间复杂度为 O(1)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::smooth_sort;
+/// let mut arr = [2, 1, 3];
+/// smooth_sort(&mut arr);
+/// assert_eq!(arr, [1, 2, 3]);
+/// ```
+pub fn smooth_sort<T>(arr: &mut [T])
+where
+    T: Ord,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    // p 的第 i 位表示当前森林中是否存在阶数为 pshift + i 的 Leonardo 堆
+    let mut p: u128 = 1;
+    let mut pshift = 1;
+    let mut head = 0;
+
+    // 构建 Leonardo 堆森林
+    while head < arr.len() - 1 {
+        if p & 3 == 3 {
+            // 相邻阶数的两个堆与新元素合并为一个更大的堆
+            sift(arr, pshift, head);
+            p >>= 2;
+            pshift += 2;
+        } else {
+            if LEONARDO[pshift - 1] >= arr.len() - 1 - head {
+                // 该堆不会再被合并，需要调整各堆的根节点使其有序
+                trinkle(arr, p, pshift, head, false);
+            } else {
+                sift(arr, pshift, head);
+            }
+
+            if pshift == 1 {
+                p <<= 1;
+                pshift -= 1;
+            } else {
+                p <<= pshift - 1;
+                pshift = 1;
+            }
+        }
+        p |= 1;
+        head += 1;
+    }
+    trinkle(arr, p, pshift, head, false);
+
+    // 依次移除最右侧的根节点（即当前最大值）
+    while pshift != 1 || p != 1 {
+        if pshift <= 1 {
+            let trail = (p & !1).trailing_zeros() as usize;
+            p >>= trail;
+            pshift += trail;
+        } else {
+            // 拆分为两个子堆后，分别将其根节点调整到正确位置
+            p <<= 2;
+            p ^= 7;
+            pshift -= 2;
+            trinkle(arr, p >> 1, pshift + 1, head - LEONARDO[pshift] - 1, true);
+            trinkle(arr, p, pshift, head - 1, true);
+        }
+        head -= 1;
+    }
+}
+
+/// Leonardo 数：L(0) = L(1) = 1，L(k) = L(k - 1) + L(k - 2) + 1
+const LEONARDO: [usize; 92] = leonardo_numbers();
+
+const fn leonardo_numbers() -> [usize; 92] {
+    let mut result: [usize; 92] = [1; 92];
+    let mut k = 2;
+    while k < result.len() {
+        result[k] = result[k - 1]
+            .saturating_add(result[k - 2])
+            .saturating_add(1);
+        k += 1;
+    }
+    result
+}
+
+/// 在以 head 为根节点、阶数为 pshift 的 Leonardo 堆中下沉根节点
+fn sift<T>(arr: &mut [T], mut pshift: usize, mut head: usize)
+where
+    T: Ord,
+{
+    while pshift > 1 {
+        let right = head - 1;
+        let left = head - 1 - LEONARDO[pshift - 2];
+        if arr[head] >= arr[left] && arr[head] >= arr[right] {
+            break;
+        }
+
+        if arr[left] >= arr[right] {
+            arr.swap(head, left);
+            head = left;
+            pshift -= 1;
+        } else {
+            arr.swap(head, right);
+            head = right;
+            pshift -= 2;
+        }
+    }
+}
+
+/// 将 head 处的根节点与左侧各堆的根节点比较并交换，使各根节点从左到右保持有序
+///
+/// trusty 为 true 时表示 head 所在堆本身已满足堆性质，无需与其子节点比较。
+fn trinkle<T>(arr: &mut [T], mut p: u128, mut pshift: usize, mut head: usize, mut trusty: bool)
+where
+    T: Ord,
+{
+    while p != 1 {
+        let stepson = head - LEONARDO[pshift];
+        if arr[stepson] <= arr[head] {
+            break;
+        }
+
+        if !trusty && pshift > 1 {
+            let right = head - 1;
+            let left = head - 1 - LEONARDO[pshift - 2];
+            if arr[right] >= arr[stepson] || arr[left] >= arr[stepson] {
+                break;
+            }
+        }
+
+        arr.swap(head, stepson);
+        head = stepson;
+        let trail = (p & !1).trailing_zeros() as usize;
+        p >>= trail;
+        pshift += trail;
+        trusty = false;
+    }
+
+    if !trusty {
+        sift(arr, pshift, head);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{is_sort, random_vec};
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn empty() {
+        let mut arr: Vec<i32> = vec![];
+        smooth_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn reverse_sorted() {
+        let mut arr = vec![6, 5, 4, 3, 2, 1];
+        smooth_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn sorted() {
+        let mut arr = vec![1, 2, 3, 4, 5, 6];
+        smooth_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn one_element() {
+        let mut arr = vec![1];
+        smooth_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn repeat() {
+        let mut arr = vec![2, 2, 1, 3, 3, 1];
+        smooth_sort(&mut arr);
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn random() {
+        for len in 0..200 {
+            let mut arr = random_vec(len, len as u64 + 1);
+            let mut expected = arr.clone();
+            expected.sort();
+            smooth_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+}
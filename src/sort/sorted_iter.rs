@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// 按从小到大的顺序惰性返回元素的迭代器
+///
+/// 创建时以 O(n) 的时间将数组原地构造为最小堆，之后每次迭代弹出堆顶元素并下沉，耗时 O(logn)。
+/// 只取前 k 个元素时总时间复杂度为 O(n + klogn)，适合配合 [`Iterator::take`] 求最小的若干个元素。
+/// 相等元素的返回顺序不确定。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::sorted_iter;
+/// let smallest: Vec<i32> = sorted_iter(vec![5, 1, 4, 2, 3]).take(2).collect();
+/// assert_eq!(smallest, [1, 2]);
+/// ```
+pub fn sorted_iter<T>(vec: Vec<T>) -> SortedIter<T>
+where
+    T: Ord,
+{
+    let mut heap = vec;
+    if heap.len() > 1 {
+        for k in (0..=((heap.len() - 2) / 2)).rev() {
+            sink(&mut heap, k);
+        }
+    }
+    SortedIter { heap }
+}
+
+/// [`sorted_iter`] 返回的迭代器，按从小到大的顺序弹出堆中的元素
+pub struct SortedIter<T> {
+    heap: Vec<T>,
+}
+
+impl<T> Iterator for SortedIter<T>
+where
+    T: Ord,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        // 最后一个元素移到堆顶后下沉
+        let min = self.heap.swap_remove(0);
+        if !self.heap.is_empty() {
+            sink(&mut self.heap, 0);
+        }
+        Some(min)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.heap.len(), Some(self.heap.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for SortedIter<T> where T: Ord {}
+
+impl<T> FusedIterator for SortedIter<T> where T: Ord {}
+
+/// 最小堆的下沉操作
+fn sink<T>(arr: &mut [T], mut root: usize)
+where
+    T: Ord,
+{
+    loop {
+        let mut idx = 2 * root + 1;
+        if idx >= arr.len() {
+            return;
+        }
+        if let Some(right) = arr.get(idx + 1) {
+            if *right < arr[idx] {
+                idx += 1;
+            }
+        }
+
+        if arr[idx] >= arr[root] {
+            return;
+        }
+        arr.swap(idx, root);
+        root = idx;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{is_sort, random_vec};
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn empty() {
+        let mut iter = sorted_iter(Vec::<i32>::new());
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn reverse_sorted() {
+        let arr: Vec<i32> = sorted_iter(vec![5, 4, 3, 2, 1]).collect();
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn sorted() {
+        let arr: Vec<i32> = sorted_iter(vec![1, 2, 3, 4, 5]).collect();
+        assert!(is_sort(&arr));
+    }
+
+    #[test]
+    fn one_element() {
+        let arr: Vec<i32> = sorted_iter(vec![1]).collect();
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn repeat() {
+        let arr: Vec<i32> = sorted_iter(vec![2, 2, 1, 3, 3, 1]).collect();
+        assert_eq!(arr, [1, 1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn random() {
+        let mut expected = random_vec(1000, 35);
+        let arr: Vec<u64> = sorted_iter(expected.clone()).collect();
+        expected.sort();
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut iter = sorted_iter(vec![3, 1, 2]);
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+
+        let take = sorted_iter(vec![3, 1, 2]).take(2);
+        assert_eq!(take.size_hint(), (2, Some(2)));
+        assert_eq!(take.collect::<Vec<_>>(), [1, 2]);
+    }
+
+    /// 比较时累加共享的计数器
+    struct Counted<'a>(u64, &'a Cell<usize>);
+
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Counted<'_> {}
+
+    impl PartialOrd for Counted<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Counted<'_> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.1.set(self.1.get() + 1);
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn lazy() {
+        let n = 100_000;
+        let k = 10;
+        let comparisons = Cell::new(0);
+        let arr: Vec<Counted> = random_vec(n, 7)
+            .into_iter()
+            .map(|it| Counted(it, &comparisons))
+            .collect();
+
+        let smallest: Vec<u64> = sorted_iter(arr).take(k).map(|it| it.0).collect();
+        let comparisons = comparisons.get();
+
+        let mut expected = random_vec(n, 7);
+        expected.sort();
+        assert_eq!(smallest, expected[..k]);
+        // 建堆最多 2n 次比较，每次弹出最多 2logn 次比较
+        assert!(comparisons <= 2 * n + k * 2 * 17);
+    }
+}
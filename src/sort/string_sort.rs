@@ -0,0 +1,339 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// 多键快速排序（三向字符串快速排序）
+///
+/// Bentley-Sedgewick 算法，每次只比较所有字符串在同一位置上的一个字节，
+/// 公共前缀只需比较一次，适合排序 URL、文件路径等前缀重复较多的字符串。
+/// 不稳定排序，平均时间复杂度为 O(nlogn + D)，其中 D 为区分所有字符串所需的字节数，
+/// 空间复杂度为 O(logn + W)，W 为最长字符串的长度。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::multikey_quick_sort;
+/// let mut arr = ["b/a", "a/b", "a/a"];
+/// multikey_quick_sort(&mut arr);
+/// assert_eq!(arr, ["a/a", "a/b", "b/a"]);
+/// ```
+pub fn multikey_quick_sort<T>(arr: &mut [T])
+where
+    T: AsRef<[u8]>,
+{
+    multikey(arr, 0);
+}
+
+/// 数组长度小于该值时改用插入排序
+const INSERTION_THRESHOLD: usize = 10;
+
+fn multikey<T>(arr: &mut [T], depth: usize)
+where
+    T: AsRef<[u8]>,
+{
+    if arr.len() <= INSERTION_THRESHOLD {
+        insertion(arr, depth);
+        return;
+    }
+
+    let mid = median(arr, 0, arr.len() / 2, arr.len() - 1, depth);
+    arr.swap(0, mid);
+    let pivot = byte_at(&arr[0], depth);
+
+    // 三向切分：arr[..lt] < pivot，arr[lt..gt] == pivot，arr[gt..] > pivot
+    let mut lt = 0;
+    let mut gt = arr.len();
+    let mut idx = 1;
+    while idx < gt {
+        let cur = byte_at(&arr[idx], depth);
+        if cur < pivot {
+            arr.swap(lt, idx);
+            lt += 1;
+            idx += 1;
+        } else if cur > pivot {
+            gt -= 1;
+            arr.swap(idx, gt);
+        } else {
+            idx += 1;
+        }
+    }
+
+    multikey(&mut arr[..lt], depth);
+    // 所有字符串都已结束时，中间部分完全相等，无需继续排序
+    if pivot.is_some() {
+        multikey(&mut arr[lt..gt], depth + 1);
+    }
+    multikey(&mut arr[gt..], depth);
+}
+
+/// 获取字符串指定位置的字节，超出长度时返回 None，且 None 小于任意字节
+fn byte_at<T>(s: &T, depth: usize) -> Option<u8>
+where
+    T: AsRef<[u8]>,
+{
+    s.as_ref().get(depth).copied()
+}
+
+fn median<T>(arr: &[T], idx1: usize, idx2: usize, idx3: usize, depth: usize) -> usize
+where
+    T: AsRef<[u8]>,
+{
+    let (b1, b2, b3) = (
+        byte_at(&arr[idx1], depth),
+        byte_at(&arr[idx2], depth),
+        byte_at(&arr[idx3], depth),
+    );
+    if b1 < b2 {
+        if b2 < b3 {
+            return idx2;
+        }
+        if b1 < b3 {
+            return idx3;
+        }
+        return idx1;
+    }
+    if b1 < b3 {
+        return idx1;
+    }
+    if b2 < b3 {
+        return idx3;
+    }
+    idx2
+}
+
+/// 已知所有字符串的前 depth 个字节相同，从 depth 开始比较的插入排序
+fn insertion<T>(arr: &mut [T], depth: usize)
+where
+    T: AsRef<[u8]>,
+{
+    for x in 1..arr.len() {
+        let mut y = x;
+        while y > 0 && arr[y].as_ref()[depth..] < arr[y - 1].as_ref()[depth..] {
+            arr.swap(y, y - 1);
+            y -= 1;
+        }
+    }
+}
+
+/// 基于最长公共前缀（LCP）的归并排序
+///
+/// 归并时记录每个字符串与前一个输出字符串的 LCP 长度，LCP 不同时无需比较即可确定大小，
+/// LCP 相同时也只需从公共前缀之后开始比较，每个字节最多被完整比较一次。
+/// 稳定排序，平均时间复杂度为 O(nlogn + D)，空间复杂度为 O(n)。
+///
+/// # Example
+///
+/// ```
+/// use rust_demo::sort::lcp_merge_sort;
+/// let mut arr = vec![
+///     String::from("/usr/lib"),
+///     String::from("/usr/bin"),
+///     String::from("/etc"),
+/// ];
+/// lcp_merge_sort(&mut arr);
+/// assert_eq!(arr, ["/etc", "/usr/bin", "/usr/lib"]);
+/// ```
+pub fn lcp_merge_sort<T>(arr: &mut [T])
+where
+    T: AsRef<[u8]>,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mut order = {
+        let keys: Vec<&[u8]> = arr.iter().map(|s| s.as_ref()).collect();
+        lcp_sort(&keys, 0, keys.len())
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>()
+    };
+
+    // 按排序后的下标顺序沿置换环交换元素
+    for start in 0..order.len() {
+        let mut cur = start;
+        loop {
+            let next = order[cur];
+            order[cur] = cur;
+            if next == start {
+                break;
+            }
+            arr.swap(cur, next);
+            cur = next;
+        }
+    }
+}
+
+/// 排序 keys[start..end]，返回 (下标, 与前一个字符串的 LCP) 序列，第一个元素的 LCP 为 0
+fn lcp_sort(keys: &[&[u8]], start: usize, end: usize) -> Vec<(usize, usize)> {
+    if end - start == 1 {
+        return vec![(start, 0)];
+    }
+
+    let mid = start + (end - start) / 2;
+    let left = lcp_sort(keys, start, mid);
+    let right = lcp_sort(keys, mid, end);
+    lcp_merge(keys, &left, &right)
+}
+
+fn lcp_merge(
+    keys: &[&[u8]],
+    left: &[(usize, usize)],
+    right: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    let mut result = Vec::with_capacity(left.len() + right.len());
+    let mut left_idx = 0;
+    let mut right_idx = 0;
+    // 两侧当前字符串与最后一个输出字符串的 LCP
+    let mut left_lcp = 0;
+    let mut right_lcp = 0;
+
+    while left_idx < left.len() && right_idx < right.len() {
+        if left_lcp > right_lcp {
+            result.push((left[left_idx].0, left_lcp));
+            left_idx += 1;
+            left_lcp = left.get(left_idx).map_or(0, |it| it.1);
+        } else if left_lcp < right_lcp {
+            result.push((right[right_idx].0, right_lcp));
+            right_idx += 1;
+            right_lcp = right.get(right_idx).map_or(0, |it| it.1);
+        } else {
+            let l = keys[left[left_idx].0];
+            let r = keys[right[right_idx].0];
+            let lcp = left_lcp + common_prefix(&l[left_lcp..], &r[left_lcp..]);
+            if l[lcp..] <= r[lcp..] {
+                result.push((left[left_idx].0, left_lcp));
+                left_idx += 1;
+                left_lcp = left.get(left_idx).map_or(0, |it| it.1);
+                right_lcp = lcp;
+            } else {
+                result.push((right[right_idx].0, right_lcp));
+                right_idx += 1;
+                right_lcp = right.get(right_idx).map_or(0, |it| it.1);
+                left_lcp = lcp;
+            }
+        }
+    }
+
+    if left_idx < left.len() {
+        result.push((left[left_idx].0, left_lcp));
+        result.extend_from_slice(&left[left_idx + 1..]);
+    }
+    if right_idx < right.len() {
+        result.push((right[right_idx].0, right_lcp));
+        result.extend_from_slice(&right[right_idx + 1..]);
+    }
+    result
+}
+
+fn common_prefix(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{is_sort, random_vec};
+    use super::*;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn random_strings(len: usize, seed: u64) -> Vec<String> {
+        let prefixes = ["", "https://example.com/", "/usr/local/", "log:2024-01-01T"];
+        random_vec(len, seed)
+            .into_iter()
+            .map(|n| format!("{}{:o}", prefixes[n as usize % prefixes.len()], n))
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let mut arr1: Vec<&str> = vec![];
+        multikey_quick_sort(&mut arr1);
+        assert!(is_sort(&arr1));
+
+        let mut arr2: Vec<&str> = vec![];
+        lcp_merge_sort(&mut arr2);
+        assert!(is_sort(&arr2));
+    }
+
+    #[test]
+    fn reverse_sorted() {
+        let mut arr1 = vec!["f", "e", "d", "c", "b", "a"];
+        multikey_quick_sort(&mut arr1);
+        assert!(is_sort(&arr1));
+
+        let mut arr2 = vec!["f", "e", "d", "c", "b", "a"];
+        lcp_merge_sort(&mut arr2);
+        assert!(is_sort(&arr2));
+    }
+
+    #[test]
+    fn sorted() {
+        let mut arr1 = vec!["a", "ab", "abc", "b", "ba", "c"];
+        multikey_quick_sort(&mut arr1);
+        assert!(is_sort(&arr1));
+
+        let mut arr2 = vec!["a", "ab", "abc", "b", "ba", "c"];
+        lcp_merge_sort(&mut arr2);
+        assert!(is_sort(&arr2));
+    }
+
+    #[test]
+    fn one_element() {
+        let mut arr1 = vec!["a"];
+        multikey_quick_sort(&mut arr1);
+        assert!(is_sort(&arr1));
+
+        let mut arr2 = vec!["a"];
+        lcp_merge_sort(&mut arr2);
+        assert!(is_sort(&arr2));
+    }
+
+    #[test]
+    fn repeat() {
+        let mut arr1 = vec!["b", "", "ab", "b", "", "ab", "a"];
+        multikey_quick_sort(&mut arr1);
+        assert!(is_sort(&arr1));
+
+        let mut arr2 = vec!["b", "", "ab", "b", "", "ab", "a"];
+        lcp_merge_sort(&mut arr2);
+        assert!(is_sort(&arr2));
+    }
+
+    #[test]
+    fn random() {
+        for len in [0, 1, 2, 10, 11, 100, 1000] {
+            let mut expected = random_strings(len, len as u64 + 1);
+            let mut arr1 = expected.clone();
+            let mut arr2: Vec<Vec<u8>> = expected.iter().map(|s| s.clone().into_bytes()).collect();
+            expected.sort();
+
+            multikey_quick_sort(&mut arr1);
+            assert_eq!(arr1, expected);
+
+            lcp_merge_sort(&mut arr2);
+            assert!(arr2
+                .iter()
+                .map(|s| s.as_slice())
+                .eq(expected.iter().map(|s| s.as_bytes())));
+        }
+    }
+
+    #[test]
+    fn lcp_merge_sort_is_stable() {
+        let mut arr = vec![("b", 0), ("a", 1), ("b", 2), ("a", 3)];
+        let mut keys: Vec<Key> = arr.iter().map(|&(s, i)| Key(s, i)).collect();
+        lcp_merge_sort(&mut keys);
+        arr.sort_by_key(|it| it.0);
+        assert!(keys.iter().map(|k| (k.0, k.1)).eq(arr));
+    }
+
+    struct Key(&'static str, i32);
+
+    impl AsRef<[u8]> for Key {
+        fn as_ref(&self) -> &[u8] {
+            self.0.as_bytes()
+        }
+    }
+}